@@ -0,0 +1,10 @@
+use ic_cdk::query;
+
+use types::AddressBookEntryEntry;
+
+use crate::{helpers::guards::can_view, logic::AddressBookLogic};
+
+#[query(guard = "can_view")]
+pub fn get_address_book() -> Vec<AddressBookEntryEntry> {
+    AddressBookLogic::get_entries()
+}
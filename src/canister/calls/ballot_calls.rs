@@ -0,0 +1,29 @@
+use ic_cdk::{caller, query, update};
+
+use types::{BallotKeyEntry, ProposalEntry, SignatureScheme, SignedBallot};
+
+use crate::{
+    helpers::guards::{can_view, is_authorized, is_whitelisted},
+    logic::{BallotLogic, OwnerLogic},
+    result::CanisterResult,
+};
+
+#[query(guard = "can_view")]
+pub fn get_ballot_keys() -> Vec<BallotKeyEntry> {
+    BallotLogic::get_keys()
+}
+
+#[update(guard = "is_whitelisted")]
+pub fn register_ballot_key(
+    scheme: SignatureScheme,
+    public_key: Vec<u8>,
+) -> CanisterResult<BallotKeyEntry> {
+    OwnerLogic::record_activity(caller())?;
+    BallotLogic::register_key(caller(), scheme, public_key)
+}
+
+/// Submits a ballot signed offline by a member, can be called by any relayer
+#[update(guard = "is_authorized")]
+pub fn submit_signed_vote(ballot: SignedBallot) -> CanisterResult<ProposalEntry> {
+    BallotLogic::submit(ballot)
+}
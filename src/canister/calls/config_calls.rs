@@ -0,0 +1,10 @@
+use ic_cdk::query;
+
+use types::Config;
+
+use crate::{helpers::guards::can_view, logic::ConfigLogic};
+
+#[query(guard = "can_view")]
+pub fn get_config() -> Config {
+    ConfigLogic::get()
+}
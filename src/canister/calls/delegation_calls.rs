@@ -0,0 +1,27 @@
+use candid::Principal;
+use ic_cdk::{caller, query, update};
+
+use types::DelegationEntry;
+
+use crate::{
+    helpers::guards::{can_view, is_whitelisted},
+    logic::{DelegationLogic, OwnerLogic},
+    result::CanisterResult,
+};
+
+#[query(guard = "can_view")]
+pub fn get_delegations(active: Option<bool>) -> Vec<DelegationEntry> {
+    DelegationLogic::get_delegations(active)
+}
+
+#[update(guard = "is_whitelisted")]
+pub fn delegate_vote(delegate: Principal, expires_at: u64) -> CanisterResult<DelegationEntry> {
+    OwnerLogic::record_activity(caller())?;
+    DelegationLogic::delegate(caller(), delegate, expires_at)
+}
+
+#[update(guard = "is_whitelisted")]
+pub fn revoke_delegation(id: u64) -> CanisterResult<DelegationEntry> {
+    OwnerLogic::record_activity(caller())?;
+    DelegationLogic::revoke(caller(), id)
+}
@@ -0,0 +1,23 @@
+use ic_cdk::{caller, query, update};
+
+use types::GroupMemberEntry;
+
+use crate::{
+    helpers::guards::{can_refresh_group, can_view},
+    logic::{GroupLogic, OwnerLogic, PauseLogic},
+    result::CanisterResult,
+};
+
+/// The members of the group as cached from the proxy canister
+#[query(guard = "can_view")]
+pub fn get_group_members() -> Vec<GroupMemberEntry> {
+    GroupLogic::get_members()
+}
+
+#[update(guard = "can_refresh_group")]
+pub async fn refresh_group_members() -> CanisterResult<Vec<GroupMemberEntry>> {
+    PauseLogic::check_unpaused()?;
+    OwnerLogic::record_activity(caller())?;
+    GroupLogic::refresh().await?;
+    Ok(GroupLogic::get_members())
+}
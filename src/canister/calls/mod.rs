@@ -1,4 +1,14 @@
+pub mod address_book_calls;
 pub mod airdrop_calls;
+pub mod ballot_calls;
+pub mod config_calls;
+pub mod delegation_calls;
+pub mod group_calls;
 pub mod owner_calls;
+pub mod pause_calls;
 pub mod proposal_calls;
+pub mod recipient_list_calls;
+pub mod role_calls;
+pub mod rotation_calls;
+pub mod spending_limit_calls;
 pub mod whitelist_calls;
@@ -1,13 +1,14 @@
 use candid::Principal;
-use ic_cdk::{query, update};
+use ic_cdk::{caller, query, update};
+use types::{Ownership, PendingOwner, RecoverySupportEntry};
 
 use crate::{
-    helpers::guards::{is_authorized, is_wallet_index},
-    logic::OwnerLogic,
+    helpers::guards::{can_view, is_authorized, is_owner, is_wallet_index, is_whitelisted},
+    logic::{OwnerLogic, PauseLogic},
     result::CanisterResult,
 };
 
-#[query(guard = "is_authorized")]
+#[query(guard = "can_view")]
 pub fn get_owner() -> CanisterResult<Principal> {
     OwnerLogic::get()
 }
@@ -17,3 +18,32 @@ pub fn set_owner(new_owner: Principal) -> CanisterResult<Principal> {
     OwnerLogic::set(new_owner)?;
     Ok(ic_cdk::id())
 }
+
+#[query(guard = "can_view")]
+pub fn get_ownership() -> Ownership {
+    OwnerLogic::get_ownership()
+}
+
+#[query(guard = "can_view")]
+pub fn get_recovery_support() -> Vec<RecoverySupportEntry> {
+    OwnerLogic::get_recovery_support()
+}
+
+#[update(guard = "is_owner")]
+pub fn propose_owner(new_owner: Principal) -> CanisterResult<PendingOwner> {
+    PauseLogic::check_unpaused()?;
+    OwnerLogic::propose_owner(caller(), new_owner)
+}
+
+/// Called by the principal the ownership was handed over to
+#[update(guard = "is_authorized")]
+pub fn accept_ownership() -> CanisterResult<Vec<Principal>> {
+    PauseLogic::check_unpaused()?;
+    OwnerLogic::accept_ownership(caller())
+}
+
+#[update(guard = "is_whitelisted")]
+pub fn support_owner_recovery(candidate: Principal) -> CanisterResult<Vec<RecoverySupportEntry>> {
+    PauseLogic::check_unpaused()?;
+    OwnerLogic::support_recovery(caller(), candidate)
+}
@@ -0,0 +1,21 @@
+use ic_cdk::{caller, query, update};
+
+use types::PauseStatus;
+
+use crate::{
+    helpers::guards::{can_pause, can_view},
+    logic::{OwnerLogic, PauseLogic},
+    result::CanisterResult,
+};
+
+#[query(guard = "can_view")]
+pub fn get_pause_status() -> PauseStatus {
+    PauseLogic::get()
+}
+
+/// Pauses the wallet immediately, unpausing requires an approved `Unpause` proposal
+#[update(guard = "can_pause")]
+pub fn pause(reason: Option<String>) -> CanisterResult<PauseStatus> {
+    OwnerLogic::record_activity(caller())?;
+    PauseLogic::pause(caller(), reason)
+}
@@ -1,32 +1,94 @@
+use candid::Principal;
 use ic_cdk::{caller, query, update};
 
-use types::{Content, ProposalEntry, ProposalResponse, Status, VoteKind, VotesEntry};
+use types::{
+    ContactContent, Content, MemberStats, ProposalDetails, ProposalEntry, ProposalInput,
+    ProposalResponse, Status, VoteKind, VotesResponse,
+};
 
 use crate::{
-    helpers::guards::{is_authorized, is_owner, is_whitelisted},
-    logic::ProposalLogic,
+    helpers::guards::{can_execute, can_propose, can_view, can_vote, is_guardian, is_whitelisted},
+    logic::{AddressBookLogic, ProposalLogic},
     result::CanisterResult,
 };
 
-#[query(guard = "is_authorized")]
+#[query(guard = "can_view")]
 pub fn get_proposals(status: Option<Status>) -> Vec<ProposalResponse> {
     ProposalLogic::get_proposals(status)
 }
 
-#[query(guard = "is_authorized")]
-pub fn get_votes(id: u64, kind: Option<VoteKind>) -> CanisterResult<VotesEntry> {
+#[query(guard = "can_view")]
+pub fn search_proposals(query: String, status: Option<Status>) -> Vec<ProposalResponse> {
+    ProposalLogic::search_proposals(query, status)
+}
+
+/// The pending proposals the caller has not voted on yet, sorted by deadline
+#[query(guard = "is_whitelisted")]
+pub fn get_awaiting_vote() -> Vec<ProposalResponse> {
+    ProposalLogic::get_awaiting_vote(caller())
+}
+
+#[query(guard = "can_view")]
+pub fn get_member_stats(member: Option<Principal>) -> CanisterResult<Vec<MemberStats>> {
+    ProposalLogic::get_member_stats(member)
+}
+
+#[query(guard = "can_view")]
+pub fn get_votes(id: u64, kind: Option<VoteKind>) -> CanisterResult<VotesResponse> {
     ProposalLogic::get_votes(id, kind)
 }
 
-#[update(guard = "is_owner")]
+#[update(guard = "can_propose")]
 pub async fn propose(
     content: Content,
     voting_period: Option<u64>,
+    details: Option<ProposalDetails>,
+) -> CanisterResult<ProposalEntry> {
+    ProposalLogic::propose(caller(), content, voting_period, details).await
+}
+
+/// Proposes transfers to address book names, resolved to their accounts when proposed
+#[update(guard = "can_propose")]
+pub async fn propose_to_contacts(
+    content: ContactContent,
+    voting_period: Option<u64>,
+    details: Option<ProposalDetails>,
 ) -> CanisterResult<ProposalEntry> {
-    ProposalLogic::propose(caller(), content, voting_period).await
+    let content = AddressBookLogic::resolve_content(content)?;
+    ProposalLogic::propose(caller(), content, voting_period, details).await
+}
+
+#[update(guard = "can_vote")]
+pub fn vote_proposal(
+    id: u64,
+    vote: VoteKind,
+    rationale: Option<String>,
+) -> CanisterResult<ProposalEntry> {
+    ProposalLogic::vote(caller(), id, vote, rationale)
+}
+
+#[update(guard = "can_propose")]
+pub async fn propose_many(
+    proposals: Vec<ProposalInput>,
+) -> CanisterResult<Vec<CanisterResult<ProposalEntry>>> {
+    ProposalLogic::propose_many(caller(), proposals).await
+}
+
+#[update(guard = "can_vote")]
+pub fn vote_proposals(
+    votes: Vec<(u64, VoteKind, Option<String>)>,
+) -> CanisterResult<Vec<CanisterResult<ProposalEntry>>> {
+    ProposalLogic::vote_many(caller(), votes)
+}
+
+/// Stops a pending proposal from being executed
+#[update(guard = "is_guardian")]
+pub fn veto_proposal(id: u64, reason: String) -> CanisterResult<ProposalEntry> {
+    ProposalLogic::veto(id, reason)
 }
 
-#[update(guard = "is_whitelisted")]
-pub fn vote_proposal(id: u64, vote: VoteKind) -> CanisterResult<ProposalEntry> {
-    ProposalLogic::vote(caller(), id, vote)
+/// Executes a proposal of which the voting period ended, when its timer didn't succeed
+#[update(guard = "can_execute")]
+pub async fn execute_proposal(id: u64) -> CanisterResult<ProposalEntry> {
+    ProposalLogic::execute_overdue(id).await
 }
@@ -0,0 +1,10 @@
+use ic_cdk::query;
+
+use types::{RecipientList, RecipientListEntry};
+
+use crate::{helpers::guards::can_view, logic::RecipientListLogic};
+
+#[query(guard = "can_view")]
+pub fn get_recipient_lists(list: Option<RecipientList>) -> Vec<(u64, RecipientListEntry)> {
+    RecipientListLogic::get_entries(list)
+}
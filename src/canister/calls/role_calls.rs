@@ -0,0 +1,22 @@
+use candid::Principal;
+use ic_cdk::{caller, query, update};
+
+use types::{Role, RoleAssignment};
+
+use crate::{
+    helpers::guards::{can_manage_roles, can_view},
+    logic::{OwnerLogic, PauseLogic, RoleLogic},
+    result::CanisterResult,
+};
+
+#[query(guard = "can_view")]
+pub fn get_role_assignments() -> CanisterResult<Vec<RoleAssignment>> {
+    RoleLogic::get_assignments()
+}
+
+#[update(guard = "can_manage_roles")]
+pub fn assign_roles(principal: Principal, roles: Vec<Role>) -> CanisterResult<RoleAssignment> {
+    PauseLogic::check_unpaused()?;
+    OwnerLogic::record_activity(caller())?;
+    RoleLogic::assign(caller(), principal, roles)
+}
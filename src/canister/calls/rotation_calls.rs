@@ -0,0 +1,33 @@
+use candid::Principal;
+use ic_cdk::{caller, query, update};
+
+use types::RotationEntry;
+
+use crate::{
+    helpers::guards::{can_view, is_authorized, is_whitelisted},
+    logic::{OwnerLogic, RotationLogic},
+    result::CanisterResult,
+};
+
+#[query(guard = "can_view")]
+pub fn get_rotations() -> Vec<RotationEntry> {
+    RotationLogic::get_rotations()
+}
+
+#[update(guard = "is_whitelisted")]
+pub fn request_rotation(new: Principal) -> CanisterResult<RotationEntry> {
+    OwnerLogic::record_activity(caller())?;
+    RotationLogic::request(caller(), new)
+}
+
+#[update(guard = "is_whitelisted")]
+pub fn cancel_rotation() -> CanisterResult<()> {
+    OwnerLogic::record_activity(caller())?;
+    RotationLogic::cancel(caller())
+}
+
+/// Called by the new principal of the member
+#[update(guard = "is_authorized")]
+pub fn accept_rotation() -> CanisterResult<Vec<Principal>> {
+    RotationLogic::accept(caller())
+}
@@ -0,0 +1,16 @@
+use candid::Principal;
+use ic_cdk::query;
+
+use types::{SpendingAllowance, SpendingLimitEntry};
+
+use crate::{helpers::guards::can_view, logic::SpendingLimitLogic};
+
+#[query(guard = "can_view")]
+pub fn get_spending_limits() -> Vec<SpendingLimitEntry> {
+    SpendingLimitLogic::get_limits()
+}
+
+#[query(guard = "can_view")]
+pub fn get_spending_allowances(canister_id: Option<Principal>) -> Vec<SpendingAllowance> {
+    SpendingLimitLogic::get_allowances(canister_id)
+}
@@ -1,18 +1,54 @@
 use crate::{
-    helpers::guards::{is_authorized, is_owner},
+    helpers::guards::{can_view, is_authorized, is_owner},
     result::CanisterResult,
 };
 use candid::Principal;
-use ic_cdk::{query, update};
+use ic_cdk::{caller, query, update};
+use types::InvitationEntry;
 
-use crate::logic::WhitelistLogic;
+use crate::logic::{OwnerLogic, PauseLogic, WhitelistLogic};
 
-#[query(guard = "is_authorized")]
+#[query(guard = "can_view")]
 pub fn get_whitelist() -> CanisterResult<Vec<Principal>> {
     WhitelistLogic::get_whitelist()
 }
 
+/// Only the owner can change the members directly, admins could otherwise take over the wallet
 #[update(guard = "is_owner")]
 pub fn replace_whitelisted(whitelisted: Vec<Principal>) -> CanisterResult<Vec<Principal>> {
+    PauseLogic::check_unpaused()?;
+    OwnerLogic::record_activity(caller())?;
     WhitelistLogic::replace_whitelisted(whitelisted)
 }
+
+#[update(guard = "is_owner")]
+pub fn add_member(member: Principal) -> CanisterResult<Vec<Principal>> {
+    PauseLogic::check_unpaused()?;
+    OwnerLogic::record_activity(caller())?;
+    WhitelistLogic::add_member_directly(member)
+}
+
+#[update(guard = "is_owner")]
+pub fn remove_member(member: Principal) -> CanisterResult<Vec<Principal>> {
+    PauseLogic::check_unpaused()?;
+    OwnerLogic::record_activity(caller())?;
+    WhitelistLogic::remove_member_directly(member)
+}
+
+#[update(guard = "is_owner")]
+pub fn swap_member(old: Principal, new: Principal) -> CanisterResult<Vec<Principal>> {
+    PauseLogic::check_unpaused()?;
+    OwnerLogic::record_activity(caller())?;
+    WhitelistLogic::swap_member_directly(old, new)
+}
+
+#[query(guard = "can_view")]
+pub fn get_invitations() -> Vec<InvitationEntry> {
+    WhitelistLogic::get_invitations()
+}
+
+/// Called by an invited principal to become a member
+#[update(guard = "is_authorized")]
+pub fn accept_invitation() -> CanisterResult<Vec<Principal>> {
+    WhitelistLogic::accept_invitation(caller())
+}
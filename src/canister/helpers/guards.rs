@@ -1,11 +1,14 @@
 use candid::Principal;
 use ic_cdk::caller;
 
-use types::Error;
+use types::{Error, Permission, ProposalCreators, Visibility};
 
-use crate::storage::{
-    metadata_storage::MetadataStorage, CellStorage, OwnerStorage, StorageQueryable,
-    WhitelistStorage,
+use crate::{
+    logic::{ConfigLogic, GroupLogic, RoleLogic},
+    storage::{
+        metadata_storage::MetadataStorage, CellStorage, InvitationStorage, OwnerStorage,
+        StorageQueryable, WhitelistStorage,
+    },
 };
 
 pub fn is_authorized() -> Result<(), String> {
@@ -21,11 +24,12 @@ pub fn is_authorized() -> Result<(), String> {
 pub fn is_whitelisted() -> Result<(), String> {
     is_authorized()?;
 
-    if WhitelistStorage::contains(&caller()) {
+    // Invited principals only count as whitelisted after accepting the invitation
+    if WhitelistStorage::contains(&caller()) && !InvitationStorage::contains_key(caller()) {
         return Ok(());
     }
 
-    if caller() == OwnerStorage::get().map_err(|_| "Failed to get owner")? {
+    if OwnerStorage::get().is_ok_and(|owner| owner == caller()) {
         return Ok(());
     }
 
@@ -38,8 +42,7 @@ pub fn is_owner() -> Result<(), String> {
     is_authorized()?;
     is_whitelisted()?;
 
-    let owner = OwnerStorage::get().map_err(|_| "Failed to get owner")?;
-    if caller() == owner {
+    if OwnerStorage::get().is_ok_and(|owner| owner == caller()) {
         return Ok(());
     }
 
@@ -52,7 +55,7 @@ pub fn is_wallet_index() -> Result<(), String> {
     is_authorized()?;
 
     let metadata = MetadataStorage::get().map_err(|_| "Failed to get metadata")?;
-    if caller() == metadata.index_canister {
+    if metadata.index_canister == Some(caller()) {
         return Ok(());
     }
 
@@ -60,3 +63,84 @@ pub fn is_wallet_index() -> Result<(), String> {
         .add_message("Principal is not the wallet index")
         .to_string())
 }
+
+/// Reads are restricted by the configured visibility
+pub fn can_view() -> Result<(), String> {
+    match ConfigLogic::get().visibility() {
+        Visibility::Public => is_authorized(),
+        Visibility::Group if GroupLogic::has_access(caller()) => Ok(()),
+        Visibility::Group | Visibility::Members => has_permission(Permission::View),
+    }
+}
+
+pub fn is_guardian() -> Result<(), String> {
+    is_authorized()?;
+
+    if ConfigLogic::get().guardian == Some(caller()) {
+        return Ok(());
+    }
+
+    Err(Error::unauthorized()
+        .add_message("Principal is not the guardian")
+        .to_string())
+}
+
+/// Members and the guardian can pause the wallet
+pub fn can_pause() -> Result<(), String> {
+    is_guardian().or_else(|_| is_whitelisted())
+}
+
+pub fn can_propose() -> Result<(), String> {
+    is_not_guardian()?;
+
+    match ConfigLogic::get().proposal_creators() {
+        ProposalCreators::Owner => is_owner(),
+        ProposalCreators::Members => is_whitelisted(),
+        ProposalCreators::Roles => has_permission(Permission::Propose),
+        ProposalCreators::Group if GroupLogic::has_access(caller()) => is_authorized(),
+        // Without an owner the members keep proposing, like the Members setting
+        ProposalCreators::Group if OwnerStorage::get().is_err() => is_whitelisted(),
+        ProposalCreators::Group => is_owner(),
+    }
+}
+
+pub fn can_vote() -> Result<(), String> {
+    is_not_guardian()?;
+    has_permission(Permission::Vote)
+}
+
+pub fn can_execute() -> Result<(), String> {
+    is_not_guardian()?;
+    has_permission(Permission::Execute)
+}
+
+pub fn can_refresh_group() -> Result<(), String> {
+    has_permission(Permission::RefreshGroup)
+}
+
+pub fn can_manage_roles() -> Result<(), String> {
+    has_permission(Permission::ManageRoles)
+}
+
+/// The guardian can only pause the wallet and veto proposals
+fn is_not_guardian() -> Result<(), String> {
+    if is_guardian().is_ok() {
+        return Err(Error::unauthorized()
+            .add_message("The guardian cannot propose, vote or execute")
+            .to_string());
+    }
+
+    Ok(())
+}
+
+fn has_permission(permission: Permission) -> Result<(), String> {
+    is_authorized()?;
+
+    if RoleLogic::has_permission(caller(), permission) {
+        return Ok(());
+    }
+
+    Err(Error::unauthorized()
+        .add_message("Principal does not have the required role")
+        .to_string())
+}
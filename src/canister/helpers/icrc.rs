@@ -10,6 +10,14 @@ pub async fn icrc1_balance_of(canister_id: Principal, arg0: Account) -> Result<(
     ic_cdk::call(canister_id, "icrc1_balance_of", (arg0,)).await
 }
 
+pub async fn icrc1_decimals(canister_id: Principal) -> Result<(u8,)> {
+    ic_cdk::call(canister_id, "icrc1_decimals", ()).await
+}
+
+pub async fn icrc1_symbol(canister_id: Principal) -> Result<(String,)> {
+    ic_cdk::call(canister_id, "icrc1_symbol", ()).await
+}
+
 pub async fn icrc1_transfer(
     canister_id: Principal,
     arg0: TransferArg,
@@ -1,4 +1,7 @@
 pub mod guards;
 pub mod icrc;
+pub mod proxy;
+pub mod signature;
 pub mod str;
 pub mod validator;
+pub mod xrc;
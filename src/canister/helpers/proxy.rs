@@ -0,0 +1,17 @@
+use candid::{CandidType, Principal, Reserved};
+use ic_cdk::api::call::{call, CallResult as Result};
+use serde::Deserialize;
+
+/// The members of a group as returned by the proxy canister
+pub async fn get_group_members(
+    canister_id: Principal,
+    group_id: u64,
+) -> Result<(std::result::Result<Vec<JoinedMemberResponse>, Reserved>,)> {
+    call(canister_id, "get_group_members", (group_id,)).await
+}
+
+#[derive(CandidType, Deserialize, Clone)]
+pub struct JoinedMemberResponse {
+    pub principal: Principal,
+    pub roles: Vec<String>,
+}
@@ -0,0 +1,109 @@
+use types::SignatureScheme;
+
+/// Verifies a signature over the message with the public key of the given scheme
+pub fn verify(
+    scheme: &SignatureScheme,
+    public_key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    match scheme {
+        SignatureScheme::Ed25519 => verify_ed25519(public_key, message, signature),
+        SignatureScheme::Secp256k1 => verify_secp256k1(public_key, message, signature),
+    }
+}
+
+pub fn is_valid_public_key(scheme: &SignatureScheme, public_key: &[u8]) -> bool {
+    match scheme {
+        SignatureScheme::Ed25519 => ed25519_key(public_key).is_some(),
+        SignatureScheme::Secp256k1 => {
+            k256::ecdsa::VerifyingKey::from_sec1_bytes(public_key).is_ok()
+        }
+    }
+}
+
+fn ed25519_key(public_key: &[u8]) -> Option<ed25519_dalek::VerifyingKey> {
+    let bytes: &[u8; 32] = public_key.try_into().ok()?;
+    ed25519_dalek::VerifyingKey::from_bytes(bytes).ok()
+}
+
+fn verify_ed25519(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Some(key) = ed25519_key(public_key) else {
+        return false;
+    };
+
+    match ed25519_dalek::Signature::from_slice(signature) {
+        Ok(signature) => key.verify_strict(message, &signature).is_ok(),
+        Err(_) => false,
+    }
+}
+
+fn verify_secp256k1(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    use k256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+
+    let Ok(key) = VerifyingKey::from_sec1_bytes(public_key) else {
+        return false;
+    };
+
+    match Signature::from_slice(signature) {
+        Ok(signature) => key.verify(message, &signature).is_ok(),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::SignatureScheme;
+
+    use crate::helpers::signature::verify;
+
+    #[test]
+    fn test_verify() {
+        let message = b"ballot";
+
+        let key = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+        let public_key = key.verifying_key().to_bytes().to_vec();
+        let signature = ed25519_dalek::Signer::sign(&key, message)
+            .to_bytes()
+            .to_vec();
+        assert!(verify(
+            &SignatureScheme::Ed25519,
+            &public_key,
+            message,
+            &signature
+        ));
+        assert!(!verify(
+            &SignatureScheme::Ed25519,
+            &public_key,
+            b"other",
+            &signature
+        ));
+
+        let key = k256::ecdsa::SigningKey::from_slice(&[7; 32]).unwrap();
+        let public_key = key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec();
+        let signature: k256::ecdsa::Signature = k256::ecdsa::signature::Signer::sign(&key, message);
+        let signature = signature.to_bytes().to_vec();
+        assert!(verify(
+            &SignatureScheme::Secp256k1,
+            &public_key,
+            message,
+            &signature
+        ));
+        assert!(!verify(
+            &SignatureScheme::Secp256k1,
+            &public_key,
+            b"other",
+            &signature
+        ));
+        assert!(!verify(
+            &SignatureScheme::Ed25519,
+            &public_key,
+            message,
+            &signature
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+use candid::{Nat, Principal};
 use types::{Error, ValidateField, ValidationResponse, ValidationType};
 
 use super::str::str_len;
@@ -35,6 +36,12 @@ impl Validator {
             None => Ok(()),
             StringLength(value, min, max) => Self::validate_string_length(value, min, max, field),
             Count(value, min, max) => Self::validate_count(value, min, max, field),
+            NonZero(value) => Self::validate_non_zero(value, field),
+            Range(value, min, max) => Self::validate_range(value, min, max, field),
+            ExcludedPrincipal(value, excluded) => {
+                Self::validate_excluded_principal(value, excluded, field)
+            }
+            Unique(value, others) => Self::validate_unique(value, others, field),
         }
     }
 
@@ -81,4 +88,107 @@ impl Validator {
 
         Ok(())
     }
+
+    fn validate_non_zero(value: &Nat, field: &String) -> Result<(), ValidationResponse> {
+        if value == &Nat::from(0u32) {
+            return Err(ValidationResponse {
+                field: field.to_string(),
+                message: "Value must be greater than zero".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn validate_range(
+        value: &u64,
+        min: &u64,
+        max: &u64,
+        field: &String,
+    ) -> Result<(), ValidationResponse> {
+        if value < min {
+            return Err(ValidationResponse {
+                field: field.to_string(),
+                message: format!("Minimum value is {}", min),
+            });
+        }
+        if value > max {
+            return Err(ValidationResponse {
+                field: field.to_string(),
+                message: format!("Maximum value is {}", max),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn validate_excluded_principal(
+        value: &Principal,
+        excluded: &[Principal],
+        field: &String,
+    ) -> Result<(), ValidationResponse> {
+        if excluded.contains(value) {
+            return Err(ValidationResponse {
+                field: field.to_string(),
+                message: format!("Principal {} is not allowed", value),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn validate_unique(
+        value: &String,
+        others: &[String],
+        field: &String,
+    ) -> Result<(), ValidationResponse> {
+        if others.contains(value) {
+            return Err(ValidationResponse {
+                field: field.to_string(),
+                message: format!("Duplicate value {}", value),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use candid::{Nat, Principal};
+    use types::{ValidateField, ValidationType};
+
+    use crate::helpers::validator::Validator;
+
+    fn validate(validation_type: ValidationType) -> bool {
+        Validator::validate_field(&ValidateField(validation_type, "field".to_owned())).is_ok()
+    }
+
+    #[test]
+    fn test_validate_field() {
+        assert!(validate(ValidationType::NonZero(Nat::from(1u32))));
+        assert!(!validate(ValidationType::NonZero(Nat::from(0u32))));
+
+        assert!(validate(ValidationType::Range(5, 5, 10)));
+        assert!(validate(ValidationType::Range(10, 5, 10)));
+        assert!(!validate(ValidationType::Range(4, 5, 10)));
+        assert!(!validate(ValidationType::Range(11, 5, 10)));
+
+        let excluded = vec![Principal::anonymous()];
+        assert!(validate(ValidationType::ExcludedPrincipal(
+            Principal::management_canister(),
+            excluded.clone()
+        )));
+        assert!(!validate(ValidationType::ExcludedPrincipal(
+            Principal::anonymous(),
+            excluded
+        )));
+
+        let others = vec!["a".to_owned(), "b".to_owned()];
+        assert!(validate(ValidationType::Unique(
+            "c".to_owned(),
+            others.clone()
+        )));
+        assert!(!validate(ValidationType::Unique("a".to_owned(), others)));
+    }
 }
@@ -0,0 +1,84 @@
+use candid::{CandidType, Principal};
+use ic_cdk::api::call::{call_with_payment128, CallResult as Result};
+use serde::Deserialize;
+
+/// Cycles to attach to a `get_exchange_rate` call, the unused part is refunded
+pub const XRC_CALL_CYCLES: u128 = 1_000_000_000;
+
+pub async fn get_exchange_rate(
+    canister_id: Principal,
+    arg0: GetExchangeRateRequest,
+) -> Result<(GetExchangeRateResult,)> {
+    call_with_payment128(canister_id, "get_exchange_rate", (arg0,), XRC_CALL_CYCLES).await
+}
+
+#[derive(CandidType, Deserialize, Clone)]
+pub enum AssetClass {
+    Cryptocurrency,
+    FiatCurrency,
+}
+
+#[derive(CandidType, Deserialize, Clone)]
+pub struct Asset {
+    pub symbol: String,
+    pub class: AssetClass,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct GetExchangeRateRequest {
+    pub base_asset: Asset,
+    pub quote_asset: Asset,
+    pub timestamp: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ExchangeRateMetadata {
+    pub decimals: u32,
+    pub base_asset_num_received_rates: u64,
+    pub base_asset_num_queried_sources: u64,
+    pub quote_asset_num_received_rates: u64,
+    pub quote_asset_num_queried_sources: u64,
+    pub standard_deviation: u64,
+    pub forex_timestamp: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ExchangeRate {
+    pub base_asset: Asset,
+    pub quote_asset: Asset,
+    pub timestamp: u64,
+    pub rate: u64,
+    pub metadata: ExchangeRateMetadata,
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ExchangeRateError {
+    AnonymousPrincipalNotAllowed,
+    Pending,
+    CryptoBaseAssetNotFound,
+    CryptoQuoteAssetNotFound,
+    StablecoinRateNotFound,
+    StablecoinRateTooFewRates,
+    StablecoinRateZeroRate,
+    ForexInvalidTimestamp,
+    ForexBaseAssetNotFound,
+    ForexQuoteAssetNotFound,
+    ForexAssetsNotFound,
+    RateLimited,
+    NotEnoughCycles,
+    FailedToAcceptCycles,
+    InconsistentRatesReceived,
+    Other(ExchangeRateOtherError),
+}
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ExchangeRateOtherError {
+    pub code: u32,
+    pub description: String,
+}
+
+#[derive(CandidType, Deserialize)]
+pub enum GetExchangeRateResult {
+    Ok(ExchangeRate),
+    Err(ExchangeRateError),
+}
@@ -1,8 +1,8 @@
 use candid::Principal;
-use ic_cdk::{caller, init, query};
-use logic::WhitelistLogic;
+use ic_cdk::{caller, init, post_upgrade, query};
+use logic::{ConfigLogic, GroupLogic, OwnerLogic, ProposalLogic, WhitelistLogic};
 use storage::{metadata_storage::MetadataStorage, CellStorage};
-use types::Metadata;
+use types::{Config, Metadata};
 
 pub mod helpers;
 pub mod logic;
@@ -12,9 +12,35 @@ pub mod storage;
 pub mod calls;
 
 #[init]
-pub fn init(owner: Principal, whitelisted: Vec<Principal>, proxy: Principal, group_id: u64) {
-    MetadataStorage::set(Metadata::new(group_id, proxy, caller())).expect("Failed to set metadata");
-    WhitelistLogic::init(owner, whitelisted)
+pub fn init(
+    owner: Option<Principal>,
+    whitelisted: Vec<Principal>,
+    proxy: Principal,
+    group_id: u64,
+    config: Option<Config>,
+    index_canister: Option<Principal>,
+) {
+    // The index canister installs with an owner and without this argument. A standalone
+    // deployment passes neither an owner nor an index canister
+    let index_canister = index_canister.or(owner.map(|_| caller()));
+    MetadataStorage::set(Metadata::new(group_id, proxy, index_canister))
+        .expect("Failed to set metadata");
+    let config = config.unwrap_or_default();
+    let guardian = config.guardian;
+    ConfigLogic::validate(&config).expect("Invalid config");
+    ConfigLogic::set(config).expect("Failed to set config");
+    WhitelistLogic::init(owner, whitelisted);
+    ConfigLogic::validate_proposal_creators(&ConfigLogic::get()).expect("Invalid config");
+    WhitelistLogic::validate_guardian(guardian).expect("Invalid guardian");
+    GroupLogic::start_refresh_timer();
+}
+
+#[post_upgrade]
+pub fn post_upgrade() {
+    GroupLogic::start_refresh_timer();
+    WhitelistLogic::start_invitation_timers();
+    ProposalLogic::start_execution_timers();
+    OwnerLogic::init_activity().expect("Failed to initialize the owner activity");
 }
 
 // Hacky way to expose the candid interface to the outside world
@@ -22,7 +48,18 @@ pub fn init(owner: Principal, whitelisted: Vec<Principal>, proxy: Principal, gro
 pub fn __export_did_tmp_() -> String {
     use crate::result::CanisterResult;
     use types::ProposalResponse;
-    use types::{AirdropTransfers, Content, ProposalEntry, Status, VoteKind, VotesEntry};
+    use types::RotationEntry;
+    use types::{AddressBookEntryEntry, Config, SpendingAllowance, SpendingLimitEntry};
+    use types::{
+        AirdropTransfers, ContactContent, Content, ProposalEntry, Status, VoteKind, VotesResponse,
+    };
+    use types::{
+        BallotKeyEntry, InvitationEntry, MemberStats, ProposalInput, SignatureScheme, SignedBallot,
+    };
+    use types::{DelegationEntry, ProposalDetails, RecipientList, RecipientListEntry};
+    use types::{GroupMemberEntry, PauseStatus};
+    use types::{Ownership, PendingOwner, RecoverySupportEntry};
+    use types::{Role, RoleAssignment};
 
     use candid::export_service;
     export_service!();
@@ -0,0 +1,83 @@
+use icrc_ledger_types::icrc1::{account::Account, transfer::TransferArg};
+use types::{
+    Address, AddressBookEntry, AddressBookEntryEntry, AddressBookProposalContent,
+    AirdropProposalContent, ContactContent, ContactTransfer, Content, Error,
+    TransferProposalContent, ValidateField, ValidationType,
+};
+
+use crate::{
+    helpers::validator::Validator,
+    result::CanisterResult,
+    storage::{AddressBookStorage, StorageQueryable, StorageUpdateable},
+};
+
+use super::MAX_ADDRESS_BOOK_NAME_LENGTH;
+
+pub struct AddressBookLogic;
+
+impl AddressBookLogic {
+    pub fn get_entries() -> Vec<AddressBookEntryEntry> {
+        AddressBookStorage::get_all()
+    }
+
+    pub fn validate(content: &AddressBookProposalContent) -> CanisterResult<()> {
+        Validator::new(vec![ValidateField(
+            ValidationType::StringLength(content.name.clone(), 1, MAX_ADDRESS_BOOK_NAME_LENGTH),
+            "name".to_owned(),
+        )])
+        .validate()
+    }
+
+    pub fn exists(name: String) -> CanisterResult<()> {
+        AddressBookStorage::get(name).map(|_| ())
+    }
+
+    pub fn set(content: AddressBookProposalContent) -> CanisterResult<AddressBookEntryEntry> {
+        AddressBookStorage::upsert(content.name, AddressBookEntry::new(content.address))
+    }
+
+    pub fn remove(name: String) -> CanisterResult<()> {
+        AddressBookStorage::remove(name)
+    }
+
+    /// Replaces the transfers to address book names with transfers to the stored accounts
+    pub fn resolve_content(content: ContactContent) -> CanisterResult<Content> {
+        match content {
+            ContactContent::Transfer(content) => Ok(Content::Transfer(TransferProposalContent {
+                canister_id: content.canister_id,
+                args: Self::resolve_transfer(content.transfer)?,
+            })),
+            ContactContent::Airdrop(content) => Ok(Content::Airdrop(AirdropProposalContent {
+                canister_id: content.canister_id,
+                args: content
+                    .transfers
+                    .into_iter()
+                    .map(Self::resolve_transfer)
+                    .collect::<CanisterResult<_>>()?,
+            })),
+        }
+    }
+
+    fn resolve_transfer(transfer: ContactTransfer) -> CanisterResult<TransferArg> {
+        Ok(TransferArg {
+            from_subaccount: None,
+            to: Self::resolve(&transfer.name)?,
+            fee: None,
+            created_at_time: None,
+            memo: transfer.memo,
+            amount: transfer.amount,
+        })
+    }
+
+    fn resolve(name: &str) -> CanisterResult<Account> {
+        let (_, entry) = AddressBookStorage::get(name.to_owned())
+            .map_err(|e| e.add_message(&format!("Unknown address book name: {name}")))?;
+
+        match entry.address {
+            Address::Account(account) => Ok(account),
+            Address::AccountIdentifier(_) => Err(Error::unsupported().add_message(&format!(
+                "Address book name {name} is an account identifier, transfers require an account"
+            ))),
+        }
+    }
+}
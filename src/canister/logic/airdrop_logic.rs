@@ -1,14 +1,14 @@
 use candid::{Nat, Principal};
 use icrc_ledger_types::icrc1::transfer::TransferArg;
 
-use types::{AirdropProposalContent, AirdropTransfer, AirdropTransfers, Status};
+use types::{AirdropProposalContent, AirdropTransfer, AirdropTransfers, Status, UsdValuation};
 
 use crate::{
     result::CanisterResult,
     storage::{AirdropTransferStorage, StorageInsertableByKey, StorageQueryable},
 };
 
-use super::transfer_logic::TransferLogic;
+use super::{transfer_logic::TransferLogic, SpendingLimitLogic};
 
 pub struct AirdropLogic;
 
@@ -18,11 +18,29 @@ impl AirdropLogic {
         Ok(txs)
     }
 
-    pub async fn execute_airdrop(id: u64, content: AirdropProposalContent) -> CanisterResult<()> {
+    /// The spendings of all transfers are reserved before the first await, right after the
+    /// spending limits were checked by the proposal execution
+    pub async fn execute_airdrop(
+        id: u64,
+        content: AirdropProposalContent,
+        valuation: Option<UsdValuation>,
+    ) -> CanisterResult<()> {
+        let mut reserved = Vec::with_capacity(content.args.len());
+        for args in content.args {
+            let reservation = SpendingLimitLogic::reserve(
+                id,
+                content.canister_id,
+                args.to,
+                args.amount.clone(),
+                valuation.as_ref(),
+            )?;
+            reserved.push((args, reservation));
+        }
+
         let mut transfers = Vec::<AirdropTransfer>::default();
 
-        for args in content.args {
-            let is_ok = TransferLogic::transfer(content.canister_id, args.clone())
+        for (args, reservation) in reserved {
+            let is_ok = TransferLogic::transfer(content.canister_id, args.clone(), reservation)
                 .await
                 .is_ok();
 
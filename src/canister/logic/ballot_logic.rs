@@ -0,0 +1,99 @@
+use candid::Principal;
+use ic_cdk::api::{id, time};
+use types::{
+    BallotKey, BallotKeyEntry, Error, ProposalEntry, SignatureScheme, SignedBallot, ValidateField,
+    ValidationType,
+};
+
+use crate::{
+    helpers::{signature, validator::Validator},
+    result::CanisterResult,
+    storage::{BallotKeyStorage, StorageQueryable, StorageUpdateable},
+};
+
+use super::{ProposalLogic, WhitelistLogic, MAX_BALLOT_VALIDITY_IN_NANOS};
+
+pub struct BallotLogic;
+
+impl BallotLogic {
+    pub fn get_keys() -> Vec<BallotKeyEntry> {
+        BallotKeyStorage::get_all()
+    }
+
+    /// Registers the key a member uses to sign ballots, replacing a previously registered key
+    /// # Note
+    /// Keys are never removed, a compromised key is revoked by registering a new one which keeps
+    /// the nonce so ballots signed before can't be replayed
+    pub fn register_key(
+        caller: Principal,
+        scheme: SignatureScheme,
+        public_key: Vec<u8>,
+    ) -> CanisterResult<BallotKeyEntry> {
+        if !signature::is_valid_public_key(&scheme, &public_key) {
+            return Err(Error::bad_request().add_message("Invalid public key"));
+        }
+
+        if BallotKeyStorage::find_owner(&scheme, &public_key).is_some_and(|p| p != caller) {
+            return Err(
+                Error::duplicate().add_message("Public key is registered by another member")
+            );
+        }
+
+        // Keep the nonce when the key is replaced so old ballots can't be replayed
+        let last_nonce = BallotKeyStorage::get(caller)
+            .ok()
+            .and_then(|(_, key)| key.last_nonce);
+
+        let key = BallotKey {
+            last_nonce,
+            ..BallotKey::new(scheme, public_key)
+        };
+
+        BallotKeyStorage::upsert(caller, key)
+    }
+
+    /// Casts the vote of a signed ballot on behalf of the voter
+    /// # Note
+    /// Can be submitted by anyone, the signature proves the intent of the voter
+    pub fn submit(signed: SignedBallot) -> CanisterResult<ProposalEntry> {
+        let SignedBallot {
+            voter,
+            ballot,
+            signature,
+        } = signed;
+
+        let now = time();
+        Validator::new(vec![ValidateField(
+            ValidationType::Range(
+                ballot.expires_at,
+                now + 1,
+                now.saturating_add(MAX_BALLOT_VALIDITY_IN_NANOS),
+            ),
+            "expires_at".to_owned(),
+        )])
+        .validate()?;
+
+        if !WhitelistLogic::is_member(voter) {
+            return Err(Error::unauthorized().add_message("Voter is not whitelisted"));
+        }
+
+        let (_, mut key) = BallotKeyStorage::get(voter)
+            .map_err(|_| Error::bad_request().add_message("Voter has no registered ballot key"))?;
+
+        let message = ballot.message(&id());
+        if !signature::verify(&key.scheme, &key.public_key, &message, &signature) {
+            return Err(Error::unauthorized().add_message("Invalid ballot signature"));
+        }
+
+        if key.last_nonce.is_some_and(|last| ballot.nonce <= last) {
+            return Err(Error::bad_request().add_message("Ballot nonce already used"));
+        }
+
+        let result = ProposalLogic::vote(voter, ballot.proposal_id, ballot.vote, None)?;
+
+        key.last_nonce = Some(ballot.nonce);
+        BallotKeyStorage::upsert(voter, key)?;
+
+        Ok(result)
+    }
+}
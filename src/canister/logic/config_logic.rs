@@ -0,0 +1,105 @@
+use types::{Config, Error, ProposalCreators, ValidateField, ValidationType};
+
+use super::{DAY_IN_NANOS, MAX_WHITELISTED, MAX_XRC_SYMBOL_LENGTH, MIN_WHITELISTED};
+use crate::{
+    helpers::validator::Validator,
+    result::CanisterResult,
+    storage::{CellStorage, ConfigStorage, OwnerStorage},
+};
+
+pub struct ConfigLogic;
+
+impl ConfigLogic {
+    /// Falls back to the default config for canisters installed before the config existed
+    pub fn get() -> Config {
+        ConfigStorage::get().unwrap_or_default()
+    }
+
+    pub fn set(config: Config) -> CanisterResult<Config> {
+        ConfigStorage::set(config)
+    }
+
+    pub fn validate(config: &Config) -> CanisterResult<()> {
+        let periods = &config.voting_periods();
+        let bounds = &config.member_bounds();
+        let recovery = &config.owner_recovery();
+
+        let mut fields = vec![
+            ValidateField(
+                ValidationType::Count(bounds.min as usize, MIN_WHITELISTED, bounds.max as usize),
+                "member_bounds.min".to_owned(),
+            ),
+            ValidateField(
+                ValidationType::Count(bounds.max as usize, bounds.min as usize, MAX_WHITELISTED),
+                "member_bounds.max".to_owned(),
+            ),
+            ValidateField(
+                ValidationType::Range(recovery.inactivity_period, DAY_IN_NANOS, u64::MAX),
+                "owner_recovery.inactivity_period".to_owned(),
+            ),
+            // A supermajority, at least the majority needed to approve proposals
+            ValidateField(
+                ValidationType::Count(recovery.threshold as usize, 51, 100),
+                "owner_recovery.threshold".to_owned(),
+            ),
+            ValidateField(
+                ValidationType::Range(periods.min, 1, periods.max),
+                "voting_periods.min".to_owned(),
+            ),
+            ValidateField(
+                ValidationType::Range(periods.transfer, periods.min, periods.max),
+                "voting_periods.transfer".to_owned(),
+            ),
+            ValidateField(
+                ValidationType::Range(periods.airdrop, periods.min, periods.max),
+                "voting_periods.airdrop".to_owned(),
+            ),
+            ValidateField(
+                ValidationType::Range(periods.settings, periods.min, periods.max),
+                "voting_periods.settings".to_owned(),
+            ),
+        ];
+
+        if let Some(rules) = &config.tally_rules {
+            fields.push(ValidateField(
+                ValidationType::Count(rules.quorum as usize, 1, 100),
+                "tally_rules.quorum".to_owned(),
+            ));
+            // At least a majority, otherwise a proposal could be approved and rejected at once
+            fields.push(ValidateField(
+                ValidationType::Count(rules.approval as usize, 51, 100),
+                "tally_rules.approval".to_owned(),
+            ));
+        }
+
+        for (_, symbol) in config.xrc_symbols.iter().flatten() {
+            fields.push(ValidateField(
+                ValidationType::StringLength(symbol.clone(), 1, MAX_XRC_SYMBOL_LENGTH),
+                "xrc_symbols".to_owned(),
+            ));
+        }
+
+        Validator::new(fields).validate()
+    }
+
+    /// Only a wallet with an owner can restrict the proposals to the owner, nobody could propose
+    /// otherwise
+    pub fn validate_proposal_creators(config: &Config) -> CanisterResult<()> {
+        if config.proposal_creators() == ProposalCreators::Owner && OwnerStorage::get().is_err() {
+            return Err(Error::bad_request()
+                .add_message("Only a wallet with an owner can restrict proposals to the owner"));
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_voting_period(voting_period: u64) -> CanisterResult<()> {
+        let periods = Self::get().voting_periods();
+
+        Validator::new(vec![ValidateField(
+            ValidationType::Range(voting_period, periods.min, periods.max),
+            "voting_period".to_owned(),
+        )])
+        .validate()
+    }
+}
@@ -1,5 +1,38 @@
 use std::time::Duration;
 
-pub static DAY_IN_NANOS: u64 = Duration::from_secs(24 * 60 * 60).as_nanos() as u64;
-pub static MIN_WHITELISTED: usize = 3;
-pub static MAX_WHITELISTED: usize = 3;
+pub use types::DAY_IN_NANOS;
+
+pub static GROUP_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// Cached group members give no access after missing a refresh, the proxy could be unreachable
+pub static GROUP_CACHE_VALIDITY_IN_NANOS: u64 = 2 * GROUP_REFRESH_INTERVAL.as_nanos() as u64;
+/// Limits of the configurable member bounds, including the owner
+pub static MIN_WHITELISTED: usize = 2;
+pub static MAX_WHITELISTED: usize = 50;
+pub static MAX_ADDRESS_BOOK_NAME_LENGTH: usize = 64;
+pub static MAX_XRC_SYMBOL_LENGTH: usize = 16;
+
+pub static MAX_TITLE_LENGTH: usize = 100;
+pub static MAX_DESCRIPTION_LENGTH: usize = 5000;
+pub static MAX_LINKS: usize = 10;
+pub static MAX_LINK_LENGTH: usize = 500;
+pub static MAX_RATIONALE_LENGTH: usize = 1000;
+
+pub static MAX_DELEGATION_PERIOD_IN_NANOS: u64 = 90 * DAY_IN_NANOS;
+/// Period in which the new principal has to accept a rotation
+pub static ROTATION_PERIOD_IN_NANOS: u64 = 7 * DAY_IN_NANOS;
+/// Period in which an invited principal has to accept the invitation
+pub static INVITATION_PERIOD_IN_NANOS: u64 = 14 * DAY_IN_NANOS;
+/// Period in which the new owner has to accept the ownership
+pub static OWNER_HANDOVER_PERIOD_IN_NANOS: u64 = 7 * DAY_IN_NANOS;
+
+/// Maximum memo length accepted by the ICRC-1 ledgers
+pub static MAX_MEMO_LENGTH: usize = 32;
+/// Period in which the ICRC-1 ledgers accept a `created_at_time`
+pub static TRANSACTION_WINDOW_IN_NANOS: u64 = DAY_IN_NANOS;
+pub static PERMITTED_DRIFT_IN_NANOS: u64 = Duration::from_secs(2 * 60).as_nanos() as u64;
+
+/// Signed ballots can't be valid for longer than this period
+pub static MAX_BALLOT_VALIDITY_IN_NANOS: u64 = 30 * DAY_IN_NANOS;
+
+/// Maximum number of items processed by a single batch call
+pub static MAX_BATCH_SIZE: usize = 50;
@@ -0,0 +1,99 @@
+use candid::Principal;
+use ic_cdk::{api::time, spawn};
+use types::{Delegation, DelegationEntry, Error, ValidateField, ValidationType};
+
+use crate::{
+    helpers::validator::Validator,
+    result::CanisterResult,
+    storage::{DelegationStorage, StorageInsertable, StorageQueryable, StorageUpdateable},
+};
+
+use super::{
+    notifications_logic::NotificationLogic, WhitelistLogic, MAX_DELEGATION_PERIOD_IN_NANOS,
+};
+
+pub struct DelegationLogic;
+
+impl DelegationLogic {
+    pub fn get_delegations(active: Option<bool>) -> Vec<DelegationEntry> {
+        let now = time();
+
+        DelegationStorage::filter(|_, delegation| match active {
+            Some(active) => delegation.is_active(now) == active,
+            None => true,
+        })
+    }
+
+    pub fn delegate(
+        caller: Principal,
+        delegate: Principal,
+        expires_at: u64,
+    ) -> CanisterResult<DelegationEntry> {
+        let now = time();
+
+        Validator::new(vec![ValidateField(
+            ValidationType::Range(
+                expires_at,
+                now + 1,
+                now.saturating_add(MAX_DELEGATION_PERIOD_IN_NANOS),
+            ),
+            "expires_at".to_owned(),
+        )])
+        .validate()?;
+
+        if caller == delegate {
+            return Err(Error::bad_request().add_message("Cannot delegate to yourself"));
+        }
+
+        if !WhitelistLogic::get_whitelist()?.contains(&delegate) {
+            return Err(Error::bad_request().add_message("Delegate is not whitelisted"));
+        }
+
+        if Self::find_active(caller).is_some() {
+            return Err(Error::duplicate().add_message("An active delegation already exists"));
+        }
+
+        let (id, delegation) =
+            DelegationStorage::insert(Delegation::new(caller, delegate, expires_at))?;
+
+        spawn(NotificationLogic::send_new_delegation(id));
+        Ok((id, delegation))
+    }
+
+    /// Revokes a delegation, can be done by the delegator or the delegate
+    pub fn revoke(caller: Principal, id: u64) -> CanisterResult<DelegationEntry> {
+        let (_, mut delegation) = DelegationStorage::get(id)?;
+
+        if caller != delegation.delegator && caller != delegation.delegate {
+            return Err(Error::unauthorized().add_message("Not part of the delegation"));
+        }
+
+        if !delegation.is_active(time()) {
+            return Err(Error::bad_request().add_message("Delegation is not active"));
+        }
+
+        delegation.revoke();
+        let result = DelegationStorage::update(id, delegation)?;
+
+        spawn(NotificationLogic::send_revoke_delegation(id));
+        Ok(result)
+    }
+
+    /// The active delegations between current members as `(delegator, delegate)` pairs
+    pub fn get_active_pairs() -> CanisterResult<Vec<(Principal, Principal)>> {
+        let members = WhitelistLogic::get_whitelist()?;
+
+        Ok(Self::get_delegations(Some(true))
+            .into_iter()
+            .map(|(_, delegation)| (delegation.delegator, delegation.delegate))
+            .filter(|(delegator, delegate)| {
+                members.contains(delegator) && members.contains(delegate)
+            })
+            .collect())
+    }
+
+    fn find_active(delegator: Principal) -> Option<DelegationEntry> {
+        let now = time();
+        DelegationStorage::find(|_, d| d.delegator == delegator && d.is_active(now))
+    }
+}
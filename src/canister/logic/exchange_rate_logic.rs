@@ -0,0 +1,75 @@
+use candid::{Nat, Principal};
+use types::{Error, UsdValuation};
+
+use crate::{
+    helpers::{
+        icrc::{icrc1_decimals, icrc1_symbol},
+        xrc::{
+            get_exchange_rate, Asset, AssetClass, GetExchangeRateRequest, GetExchangeRateResult,
+        },
+    },
+    result::CanisterResult,
+};
+
+use super::ConfigLogic;
+
+pub struct ExchangeRateLogic;
+
+impl ExchangeRateLogic {
+    /// The USD exchange rate of the ledger token using the configured exchange rate canister,
+    /// valuing an amount of zero. Use `UsdValuation::with_amount` to value an amount
+    pub async fn get_rate(canister_id: Principal) -> CanisterResult<UsdValuation> {
+        let config = ConfigLogic::get();
+
+        // Wrapped tokens are listed by the symbol of the underlying asset
+        let symbol = match config.xrc_symbol(canister_id) {
+            Some(symbol) => symbol,
+            None => {
+                icrc1_symbol(canister_id)
+                    .await
+                    .map_err(|(_, e)| {
+                        Error::internal().add_message(&format!("symbol failed: {e}"))
+                    })?
+                    .0
+            }
+        };
+
+        let (token_decimals,) = icrc1_decimals(canister_id)
+            .await
+            .map_err(|(_, e)| Error::internal().add_message(&format!("decimals failed: {e}")))?;
+
+        let request = GetExchangeRateRequest {
+            base_asset: Asset {
+                symbol: symbol.clone(),
+                class: AssetClass::Cryptocurrency,
+            },
+            quote_asset: Asset {
+                symbol: "USD".to_owned(),
+                class: AssetClass::FiatCurrency,
+            },
+            timestamp: None,
+        };
+
+        let (result,) = get_exchange_rate(config.exchange_rate_canister, request)
+            .await
+            .map_err(|(_, e)| {
+                Error::internal().add_message(&format!("exchange rate failed: {e}"))
+            })?;
+
+        let rate = match result {
+            GetExchangeRateResult::Ok(rate) => rate,
+            GetExchangeRateResult::Err(e) => {
+                return Err(Error::internal().add_message(&format!("exchange rate failed: {e:?}")))
+            }
+        };
+
+        Ok(UsdValuation {
+            symbol,
+            rate: rate.rate,
+            rate_decimals: rate.metadata.decimals,
+            token_decimals,
+            timestamp: rate.timestamp,
+            usd_value: Nat::from(0u32),
+        })
+    }
+}
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use candid::Principal;
+use ic_cdk::{api::time, spawn};
+use ic_cdk_timers::set_timer_interval;
+use types::{Error, GroupMember, GroupMemberEntry, ProposalCreators, Visibility};
+
+use crate::{
+    helpers::proxy::get_group_members,
+    result::CanisterResult,
+    storage::{
+        metadata_storage::MetadataStorage, CellStorage, GroupMemberStorage, StorageQueryable,
+        StorageUpdateable,
+    },
+};
+
+use super::{ConfigLogic, GROUP_CACHE_VALIDITY_IN_NANOS, GROUP_REFRESH_INTERVAL};
+
+pub struct GroupLogic;
+
+impl GroupLogic {
+    /// Refreshes the cached group members periodically, timers have to be set again after upgrades
+    pub fn start_refresh_timer() {
+        set_timer_interval(GROUP_REFRESH_INTERVAL, || spawn(Self::refresh_if_needed()));
+        ic_cdk_timers::set_timer(Duration::ZERO, || spawn(Self::refresh_if_needed()));
+    }
+
+    pub fn get_members() -> Vec<GroupMemberEntry> {
+        GroupMemberStorage::get_all()
+    }
+
+    /// Whether the principal is a member of the group with one of the configured group roles,
+    /// according to a recent refresh
+    pub fn has_access(principal: Principal) -> bool {
+        let roles = ConfigLogic::get().group_roles();
+
+        GroupMemberStorage::get_opt(principal).is_some_and(|(_, member)| {
+            member.has_access(&roles, time(), GROUP_CACHE_VALIDITY_IN_NANOS)
+        })
+    }
+
+    /// Replaces the cached group members with the members known by the proxy canister
+    pub async fn refresh() -> CanisterResult<()> {
+        let metadata = MetadataStorage::get()?;
+
+        let (result,) = get_group_members(metadata.proxy_canister, metadata.group_id)
+            .await
+            .map_err(|(_, e)| Error::internal().add_message(&format!("group members: {e}")))?;
+
+        let members = result
+            .map_err(|_| Error::internal().add_message("Proxy failed to get the group members"))?;
+
+        let refreshed_at = time();
+        GroupMemberStorage::clear();
+        for member in members {
+            GroupMemberStorage::upsert(
+                member.principal,
+                GroupMember {
+                    roles: member.roles,
+                    refreshed_at,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// The group members are only needed when reads or proposals are restricted to the group
+    async fn refresh_if_needed() {
+        let config = ConfigLogic::get();
+
+        if config.visibility() == Visibility::Group
+            || config.proposal_creators() == ProposalCreators::Group
+        {
+            if let Err(err) = Self::refresh().await {
+                ic_cdk::println!("Failed to refresh the group members: {err}");
+            }
+        }
+    }
+}
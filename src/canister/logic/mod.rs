@@ -1,14 +1,36 @@
+pub mod address_book_logic;
 pub mod airdrop_logic;
+pub mod ballot_logic;
+pub mod config_logic;
 pub mod consts;
+pub mod delegation_logic;
+pub mod exchange_rate_logic;
+pub mod group_logic;
 pub mod notifications_logic;
 pub mod owner_logic;
+pub mod pause_logic;
 pub mod proposal_logic;
+pub mod recipient_list_logic;
+pub mod role_logic;
+pub mod rotation_logic;
+pub mod spending_limit_logic;
 pub mod transfer_logic;
 pub mod whitelist_logic;
 
+pub use address_book_logic::AddressBookLogic;
 pub use airdrop_logic::AirdropLogic;
+pub use ballot_logic::BallotLogic;
+pub use config_logic::ConfigLogic;
 pub use consts::*;
+pub use delegation_logic::DelegationLogic;
+pub use exchange_rate_logic::ExchangeRateLogic;
+pub use group_logic::GroupLogic;
 pub use owner_logic::OwnerLogic;
+pub use pause_logic::PauseLogic;
 pub use proposal_logic::ProposalLogic;
+pub use recipient_list_logic::RecipientListLogic;
+pub use role_logic::RoleLogic;
+pub use rotation_logic::RotationLogic;
+pub use spending_limit_logic::SpendingLimitLogic;
 pub use transfer_logic::TransferLogic;
 pub use whitelist_logic::WhitelistLogic;
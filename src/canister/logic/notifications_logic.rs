@@ -1,5 +1,8 @@
+use candid::Principal;
 use ic_cdk::{api::call::CallResult, call, caller};
 
+use types::Metadata;
+
 use crate::storage::{metadata_storage::MetadataStorage, CellStorage};
 
 use super::WhitelistLogic;
@@ -10,9 +13,9 @@ impl NotificationLogic {
     pub async fn send_whitelist_notice() {
         if let Ok(mut whitelist) = WhitelistLogic::get_whitelist() {
             whitelist.retain(|x| x != &caller());
-            if let Ok(metadata) = MetadataStorage::get() {
+            if let Some((index_canister, metadata)) = Self::get_index_canister() {
                 let _: CallResult<((),)> = call(
-                    metadata.index_canister,
+                    index_canister,
                     "multisig_whitelist_notice_notification",
                     (whitelist, metadata.group_id),
                 )
@@ -21,12 +24,57 @@ impl NotificationLogic {
         }
     }
 
+    pub async fn send_member_joined(member: Principal) {
+        Self::send_member_notice("multisig_member_joined_notification", member).await
+    }
+
+    pub async fn send_member_invited(member: Principal) {
+        Self::send_member_notice("multisig_member_invited_notification", member).await
+    }
+
+    pub async fn send_member_left(member: Principal) {
+        Self::send_member_notice("multisig_member_left_notification", member).await
+    }
+
+    async fn send_member_notice(method: &str, member: Principal) {
+        if let Ok(mut whitelist) = WhitelistLogic::get_whitelist() {
+            whitelist.retain(|x| x != &caller());
+            // Members that left are notified as well
+            if !whitelist.contains(&member) {
+                whitelist.push(member);
+            }
+
+            if let Some((index_canister, metadata)) = Self::get_index_canister() {
+                let _: CallResult<((),)> = call(
+                    index_canister,
+                    method,
+                    (whitelist, member, metadata.group_id),
+                )
+                .await;
+            }
+        }
+    }
+
+    pub async fn send_pause_notice(paused: bool) {
+        if let Ok(mut whitelist) = WhitelistLogic::get_whitelist() {
+            whitelist.retain(|x| x != &caller());
+            if let Some((index_canister, metadata)) = Self::get_index_canister() {
+                let _: CallResult<((),)> = call(
+                    index_canister,
+                    "multisig_pause_notification",
+                    (whitelist, paused, metadata.group_id),
+                )
+                .await;
+            }
+        }
+    }
+
     pub async fn send_accept_proposal(proposal_id: u64) {
         if let Ok(mut whitelist) = WhitelistLogic::get_whitelist() {
             whitelist.retain(|x| x != &caller());
-            if let Ok(metadata) = MetadataStorage::get() {
+            if let Some((index_canister, metadata)) = Self::get_index_canister() {
                 let _: CallResult<((),)> = call(
-                    metadata.index_canister,
+                    index_canister,
                     "multisig_proposal_accept_notification",
                     (whitelist, proposal_id, metadata.group_id),
                 )
@@ -38,9 +86,9 @@ impl NotificationLogic {
     pub async fn send_decline_proposal(proposal_id: u64) {
         if let Ok(mut whitelist) = WhitelistLogic::get_whitelist() {
             whitelist.retain(|x| x != &caller());
-            if let Ok(metadata) = MetadataStorage::get() {
+            if let Some((index_canister, metadata)) = Self::get_index_canister() {
                 let _: CallResult<((),)> = call(
-                    metadata.index_canister,
+                    index_canister,
                     "multisig_proposal_decline_notification",
                     (whitelist, proposal_id, metadata.group_id),
                 )
@@ -52,9 +100,9 @@ impl NotificationLogic {
     pub async fn send_update_proposal(proposal_id: u64) {
         if let Ok(mut whitelist) = WhitelistLogic::get_whitelist() {
             whitelist.retain(|x| x != &caller());
-            if let Ok(metadata) = MetadataStorage::get() {
+            if let Some((index_canister, metadata)) = Self::get_index_canister() {
                 let _: CallResult<((),)> = call(
-                    metadata.index_canister,
+                    index_canister,
                     "multisig_proposal_status_update_notification",
                     (whitelist, proposal_id, metadata.group_id),
                 )
@@ -63,13 +111,41 @@ impl NotificationLogic {
         }
     }
 
+    pub async fn send_new_delegation(delegation_id: u64) {
+        if let Ok(mut whitelist) = WhitelistLogic::get_whitelist() {
+            whitelist.retain(|x| x != &caller());
+            if let Some((index_canister, metadata)) = Self::get_index_canister() {
+                let _: CallResult<((),)> = call(
+                    index_canister,
+                    "multisig_new_delegation_notification",
+                    (whitelist, delegation_id, metadata.group_id),
+                )
+                .await;
+            }
+        }
+    }
+
+    pub async fn send_revoke_delegation(delegation_id: u64) {
+        if let Ok(mut whitelist) = WhitelistLogic::get_whitelist() {
+            whitelist.retain(|x| x != &caller());
+            if let Some((index_canister, metadata)) = Self::get_index_canister() {
+                let _: CallResult<((),)> = call(
+                    index_canister,
+                    "multisig_delegation_revoke_notification",
+                    (whitelist, delegation_id, metadata.group_id),
+                )
+                .await;
+            }
+        }
+    }
+
     pub async fn send_new_proposal(proposal_id: u64) {
         if let Ok(mut whitelist) = WhitelistLogic::get_whitelist() {
             whitelist.retain(|x| x != &caller());
 
-            if let Ok(metadata) = MetadataStorage::get() {
+            if let Some((index_canister, metadata)) = Self::get_index_canister() {
                 let _: CallResult<((),)> = call(
-                    metadata.index_canister,
+                    index_canister,
                     "multisig_new_proposal_notification",
                     (whitelist, proposal_id, metadata.group_id),
                 )
@@ -77,4 +153,12 @@ impl NotificationLogic {
             }
         }
     }
+
+    /// Standalone deployments don't have an index canister to notify
+    fn get_index_canister() -> Option<(Principal, Metadata)> {
+        let metadata = MetadataStorage::get().ok()?;
+        metadata
+            .index_canister
+            .map(|index_canister| (index_canister, metadata))
+    }
 }
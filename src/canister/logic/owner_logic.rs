@@ -1,18 +1,156 @@
 use candid::Principal;
+use ic_cdk::api::time;
+use types::{Error, Ownership, PendingOwner, RecoverySupportEntry};
 
 use crate::{
     result::CanisterResult,
-    storage::{CellStorage, OwnerStorage},
+    storage::{
+        CellStorage, OwnerStorage, OwnershipStorage, RecoverySupportStorage, StorageQueryable,
+        StorageUpdateable,
+    },
 };
 
+use super::{ConfigLogic, WhitelistLogic, OWNER_HANDOVER_PERIOD_IN_NANOS};
+
 pub struct OwnerLogic;
 
 impl OwnerLogic {
     pub fn get() -> CanisterResult<Principal> {
-        OwnerStorage::get()
+        OwnerStorage::get().map_err(|_| Error::not_found().add_message("The wallet has no owner"))
     }
 
     pub fn set(new_owner: Principal) -> CanisterResult<Principal> {
-        OwnerStorage::set(new_owner)
+        let owner = OwnerStorage::set(new_owner)?;
+        Self::record_activity(owner)?;
+        Ok(owner)
+    }
+
+    /// Falls back to an unknown activity for canisters installed before the ownership existed
+    pub fn get_ownership() -> Ownership {
+        OwnershipStorage::get().unwrap_or_default()
+    }
+
+    /// Starts tracking the activity of an owner of which the activity is unknown, so the
+    /// inactivity period of a recovery runs from the upgrade on
+    pub fn init_activity() -> CanisterResult<()> {
+        let ownership = Self::get_ownership();
+        if OwnerStorage::get().is_err() || ownership.last_active_at.is_some() {
+            return Ok(());
+        }
+
+        OwnershipStorage::set(Ownership {
+            last_active_at: Some(time()),
+            ..ownership
+        })?;
+        Ok(())
+    }
+
+    pub fn get_recovery_support() -> Vec<RecoverySupportEntry> {
+        RecoverySupportStorage::get_all()
+    }
+
+    /// Keeps track of the activity of the owner, an active owner cancels any recovery
+    pub fn record_activity(caller: Principal) -> CanisterResult<()> {
+        if OwnerStorage::get().ok() != Some(caller) {
+            return Ok(());
+        }
+
+        OwnershipStorage::set(Ownership {
+            last_active_at: Some(time()),
+            ..Self::get_ownership()
+        })?;
+        RecoverySupportStorage::clear();
+        Ok(())
+    }
+
+    /// Hands the ownership over to a new principal, which has to accept it to prove it is
+    /// controlled by the new owner. Replaces an earlier handover
+    pub fn propose_owner(caller: Principal, new_owner: Principal) -> CanisterResult<PendingOwner> {
+        if new_owner == Principal::anonymous() || new_owner == caller {
+            return Err(Error::bad_request().add_message("Invalid new owner"));
+        }
+
+        Self::record_activity(caller)?;
+
+        let pending = PendingOwner::new(
+            new_owner,
+            time().saturating_add(OWNER_HANDOVER_PERIOD_IN_NANOS),
+        );
+        OwnershipStorage::set(Ownership {
+            pending_owner: Some(pending.clone()),
+            ..Self::get_ownership()
+        })?;
+
+        Ok(pending)
+    }
+
+    /// Accepts the ownership handed over to the caller. A whitelisted caller swaps places with the
+    /// owner, otherwise the caller takes the place of the owner
+    pub fn accept_ownership(caller: Principal) -> CanisterResult<Vec<Principal>> {
+        let ownership = Self::get_ownership();
+        let is_pending = ownership
+            .pending_owner
+            .is_some_and(|p| p.principal == caller && p.is_active(time()));
+
+        if !is_pending {
+            return Err(Error::not_found().add_message("No ownership handed over to the caller"));
+        }
+
+        let whitelist = match WhitelistLogic::is_member(caller) {
+            true => WhitelistLogic::change_owner(caller),
+            false => WhitelistLogic::rotate(Self::get()?, caller),
+        }?;
+
+        Self::reset()?;
+        Ok(whitelist)
+    }
+
+    /// Supports a whitelisted member as new owner while the owner is inactive. The ownership is
+    /// transferred once the configured share of the other members supports the same member, the
+    /// inactive owner loses its seat
+    pub fn support_recovery(
+        caller: Principal,
+        candidate: Principal,
+    ) -> CanisterResult<Vec<RecoverySupportEntry>> {
+        let owner = Self::get()?;
+        let rules = ConfigLogic::get().owner_recovery();
+
+        let is_inactive = Self::get_ownership()
+            .last_active_at
+            .is_some_and(|at| time().saturating_sub(at) >= rules.inactivity_period);
+
+        if !is_inactive {
+            return Err(Error::bad_request().add_message("Owner is not inactive"));
+        }
+
+        if caller == owner || candidate == owner || !WhitelistLogic::is_member(candidate) {
+            return Err(Error::bad_request().add_message("Invalid recovery candidate"));
+        }
+
+        RecoverySupportStorage::upsert(caller, candidate)?;
+
+        let members = WhitelistLogic::get_whitelist()?;
+        let supporters = RecoverySupportStorage::filter(|supporter, c| {
+            c == &candidate && members.contains(supporter)
+        })
+        .len();
+
+        // The owner is excluded, as the owner is inactive
+        if supporters * 100 >= rules.threshold as usize * members.len().saturating_sub(1) {
+            WhitelistLogic::recover_owner(candidate)?;
+            Self::reset()?;
+        }
+
+        Ok(Self::get_recovery_support())
+    }
+
+    /// Starts a fresh ownership for a new owner
+    fn reset() -> CanisterResult<()> {
+        OwnershipStorage::set(Ownership {
+            last_active_at: Some(time()),
+            pending_owner: None,
+        })?;
+        RecoverySupportStorage::clear();
+        Ok(())
     }
 }
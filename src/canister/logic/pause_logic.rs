@@ -0,0 +1,88 @@
+use candid::Principal;
+use ic_cdk::{api::time, spawn};
+use types::{Content, Error, PauseStatus, ValidateField, ValidationType};
+
+use crate::{
+    helpers::validator::Validator,
+    result::CanisterResult,
+    storage::{CellStorage, PauseStorage},
+};
+
+use super::{notifications_logic::NotificationLogic, ConfigLogic, MAX_RATIONALE_LENGTH};
+
+pub struct PauseLogic;
+
+impl PauseLogic {
+    pub fn get() -> PauseStatus {
+        PauseStorage::get().unwrap_or_default()
+    }
+
+    pub fn is_paused() -> bool {
+        Self::get().paused
+    }
+
+    /// Blocks the creation and execution of proposals, only an approved `Unpause` proposal
+    /// unpauses the wallet
+    pub fn pause(caller: Principal, reason: Option<String>) -> CanisterResult<PauseStatus> {
+        if Self::is_paused() {
+            return Err(Error::bad_request().add_message("Already paused"));
+        }
+
+        if let Some(reason) = &reason {
+            Validator::new(vec![ValidateField(
+                ValidationType::StringLength(reason.clone(), 0, MAX_RATIONALE_LENGTH),
+                "reason".to_owned(),
+            )])
+            .validate()?;
+        }
+
+        let status = PauseStorage::set(PauseStatus {
+            paused: true,
+            changed_by: Some(caller),
+            changed_at: Some(time()),
+            reason,
+        })?;
+
+        spawn(NotificationLogic::send_pause_notice(true));
+        Ok(status)
+    }
+
+    pub fn unpause() -> CanisterResult<()> {
+        PauseStorage::set(PauseStatus {
+            paused: false,
+            changed_by: None,
+            changed_at: Some(time()),
+            reason: None,
+        })?;
+
+        spawn(NotificationLogic::send_pause_notice(false));
+        Ok(())
+    }
+
+    /// Only proposals to unpause or to remove or replace the guardian can be created and executed
+    /// while paused, so a guardian that pauses and vetoes can be replaced
+    pub fn check(content: &Content) -> CanisterResult<()> {
+        match Self::is_escape(content) {
+            true => Ok(()),
+            false => Self::check_unpaused(),
+        }
+    }
+
+    /// The proposals that get the wallet out of a pause, these can't be vetoed either
+    pub fn is_escape(content: &Content) -> bool {
+        match content {
+            Content::Unpause => true,
+            Content::UpdateConfig(config) => config.only_changes_guardian(&ConfigLogic::get()),
+            _ => false,
+        }
+    }
+
+    /// Blocks the direct changes to the members, roles and ownership while paused
+    pub fn check_unpaused() -> CanisterResult<()> {
+        if Self::is_paused() {
+            return Err(Error::bad_request().add_message("The wallet is paused"));
+        }
+
+        Ok(())
+    }
+}
@@ -1,22 +1,29 @@
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
-use candid::Principal;
+use candid::{Nat, Principal};
 use ic_cdk::{api::time, spawn};
 use ic_cdk_timers::set_timer;
 use types::{
-    Content, Error, Proposal, ProposalEntry, ProposalResponse, Status, TallyResult, Vote, VoteKind,
-    Votes, VotesEntry,
+    Config, Content, Error, MemberStats, Proposal, ProposalDetails, ProposalEntry, ProposalInput,
+    ProposalResponse, Status, TallyResult, UsdValuation, ValidateField, ValidationType, Vote,
+    VoteHistory, VoteKind, Votes, VotesResponse,
 };
 
 use crate::{
+    helpers::validator::Validator,
     result::CanisterResult,
     storage::{
         ProposalStorage, StorageInsertable, StorageInsertableByKey, StorageQueryable,
-        StorageUpdateable, VoteStorage, WhitelistStorage,
+        StorageUpdateable, VoteHistoryStorage, VoteStorage,
     },
 };
 
-use super::{notifications_logic::NotificationLogic, AirdropLogic, TransferLogic, DAY_IN_NANOS};
+use super::{
+    notifications_logic::NotificationLogic, AddressBookLogic, AirdropLogic, ConfigLogic,
+    DelegationLogic, ExchangeRateLogic, OwnerLogic, PauseLogic, RecipientListLogic, RoleLogic,
+    SpendingLimitLogic, TransferLogic, WhitelistLogic, MAX_BATCH_SIZE, MAX_DESCRIPTION_LENGTH,
+    MAX_LINKS, MAX_LINK_LENGTH, MAX_RATIONALE_LENGTH, MAX_TITLE_LENGTH,
+};
 
 pub struct ProposalLogic;
 
@@ -25,71 +32,329 @@ impl ProposalLogic {
         ProposalStorage::get_by_status(status)
     }
 
-    pub fn get_votes(id: u64, kind: Option<VoteKind>) -> CanisterResult<VotesEntry> {
+    pub fn get_votes(id: u64, kind: Option<VoteKind>) -> CanisterResult<VotesResponse> {
         let (_, votes) = VoteStorage::get(id)?;
+        let history = VoteHistoryStorage::get_opt(id)
+            .map(|(_, history)| history.0)
+            .unwrap_or_default();
 
-        let votes = match kind {
-            Some(kind) => Votes(votes.0.iter().filter(|v| v.kind == kind).cloned().collect()),
+        let filter = |votes: Vec<Vote>| match &kind {
+            Some(kind) => votes.into_iter().filter(|v| &v.kind == kind).collect(),
             None => votes,
         };
 
-        Ok((id, votes))
+        Ok(VotesResponse {
+            id,
+            votes: Votes(filter(votes.0)),
+            history: VoteHistory(filter(history)),
+        })
+    }
+
+    pub fn search_proposals(query: String, status: Option<Status>) -> Vec<ProposalResponse> {
+        let terms = query.split_whitespace().map(String::from).collect();
+        ProposalStorage::search(terms, status)
+    }
+
+    pub fn get_awaiting_vote(caller: Principal) -> Vec<ProposalResponse> {
+        ProposalStorage::get_awaiting_vote(caller)
+    }
+
+    /// The activity statistics of the given member, or of all current members
+    pub fn get_member_stats(member: Option<Principal>) -> CanisterResult<Vec<MemberStats>> {
+        let members = WhitelistLogic::get_whitelist()?;
+        let members = match member {
+            Some(member) if members.contains(&member) => vec![member],
+            Some(_) => return Err(Error::not_found().add_message("Member not found")),
+            None => members,
+        };
+
+        let proposals = ProposalStorage::get_all_with_votes();
+        Ok(members
+            .into_iter()
+            .map(|member| MemberStats::new(member, &proposals))
+            .collect())
     }
 
     pub async fn propose(
         caller: Principal,
         content: Content,
         voting_period: Option<u64>,
+        details: Option<ProposalDetails>,
     ) -> CanisterResult<ProposalEntry> {
+        Self::create(caller, content, voting_period, details, &mut HashMap::new()).await
+    }
+
+    /// Creates a proposal, reusing the exchange rates already fetched for the ledgers in `rates`
+    async fn create(
+        caller: Principal,
+        content: Content,
+        voting_period: Option<u64>,
+        details: Option<ProposalDetails>,
+        rates: &mut HashMap<Principal, UsdValuation>,
+    ) -> CanisterResult<ProposalEntry> {
+        if let Some(details) = &details {
+            Self::validate_details(details)?;
+        }
+
+        OwnerLogic::record_activity(caller)?;
+
+        PauseLogic::check(&content)?;
+        let voting_period = voting_period
+            .unwrap_or_else(|| ConfigLogic::get().voting_periods().default_for(&content));
+        ConfigLogic::validate_voting_period(voting_period)?;
+
         match content.clone() {
             Content::Transfer(content) => {
+                TransferLogic::validate_args(std::slice::from_ref(&content.args), voting_period)?;
                 TransferLogic::check_balance(content.canister_id, &content.args.amount).await?
             }
             Content::Airdrop(content) => {
+                TransferLogic::validate_args(&content.args, voting_period)?;
                 AirdropLogic::check_balance(content.canister_id, content.args).await?
             }
+            Content::SetSpendingLimit(limit) => SpendingLimitLogic::validate(&limit)?,
+            Content::RemoveSpendingLimit(id) => SpendingLimitLogic::exists(id)?,
+            Content::UpdateConfig(config) => {
+                ConfigLogic::validate(&config)?;
+                ConfigLogic::validate_proposal_creators(&config)?;
+                WhitelistLogic::validate_guardian(config.guardian)?
+            }
+            Content::SetAddressBookEntry(content) => AddressBookLogic::validate(&content)?,
+            Content::RemoveAddressBookEntry(name) => AddressBookLogic::exists(name)?,
+            Content::AddRecipient(content) => RecipientListLogic::validate_add(&content)?,
+            Content::RemoveRecipient(content) => RecipientListLogic::validate_remove(&content)?,
+            Content::AddMember(member) => WhitelistLogic::validate_add(member)?,
+            Content::RemoveMember(member) => WhitelistLogic::validate_remove(member)?,
+            Content::ReplaceMember(content) => {
+                WhitelistLogic::validate_replace(content.old, content.new)?
+            }
+            Content::UpdateTallyRules(rules) => ConfigLogic::validate(&Config {
+                tally_rules: Some(rules),
+                ..ConfigLogic::get()
+            })?,
+            Content::ChangeOwner(owner) => WhitelistLogic::validate_owner_change(owner)?,
+            Content::Unpause if !PauseLogic::is_paused() => {
+                return Err(Error::bad_request().add_message("The wallet is not paused"))
+            }
+            Content::Unpause => {}
+            Content::AssignRoles(content) => {
+                RoleLogic::validate_assignment(content.principal, &content.roles)?
+            }
         }
 
-        let voting_period = voting_period.unwrap_or(DAY_IN_NANOS);
+        let valuation = Self::get_valuation(&content, rates).await?;
+        let threshold = Self::check_transfers(&content, valuation.as_ref())?;
+
+        // The wallet could have been paused while waiting for the ledgers
+        PauseLogic::check(&content)?;
 
-        let (id, proposal) =
-            ProposalStorage::insert(Proposal::new(caller, content, voting_period))?;
+        let (id, proposal) = ProposalStorage::insert(Proposal::new(
+            caller,
+            content,
+            voting_period,
+            threshold,
+            valuation,
+            details,
+        ))?;
 
-        set_timer(Duration::from_nanos(voting_period), move || {
-            spawn(async move {
-                let _ = Self::execute(id).await;
-            });
-        });
+        Self::set_execution_timer(id, proposal.deadline());
 
         spawn(NotificationLogic::send_new_proposal(id));
-        VoteStorage::insert_by_key(id, Votes(vec![Vote::new(caller, VoteKind::Approve)]))?;
+
+        // Creating a proposal counts as an approval, but only the votes of members are tallied
+        let votes = match WhitelistLogic::is_member(caller) {
+            true => vec![Vote::new(caller, VoteKind::Approve, None)],
+            false => vec![],
+        };
+        VoteStorage::insert_by_key(id, Votes(votes.clone()))?;
+        for vote in votes {
+            VoteHistoryStorage::append(id, vote)?;
+        }
 
         Ok((id, proposal))
     }
 
-    pub fn vote(caller: Principal, id: u64, vote: VoteKind) -> CanisterResult<ProposalEntry> {
+    /// Vetoes a proposal that wasn't executed yet, including proposals of which the voting
+    /// period ended while paused
+    pub fn veto(id: u64, reason: String) -> CanisterResult<ProposalEntry> {
+        Validator::new(vec![ValidateField(
+            ValidationType::StringLength(reason.clone(), 1, MAX_RATIONALE_LENGTH),
+            "reason".to_owned(),
+        )])
+        .validate()?;
+
+        let (_, proposal) = ProposalStorage::get(id)?;
+        if proposal.status != Status::Pending {
+            return Err(Error::bad_request().add_message("Proposal is not pending"));
+        }
+
+        // The guardian could otherwise keep the wallet paused forever
+        if PauseLogic::is_escape(&proposal.content) {
+            return Err(Error::bad_request()
+                .add_message("Proposals to unpause or to replace the guardian can't be vetoed"));
+        }
+
+        let result = ProposalStorage::veto(id, reason, time())?;
+        spawn(NotificationLogic::send_decline_proposal(id));
+        Ok(result)
+    }
+
+    pub fn vote(
+        caller: Principal,
+        id: u64,
+        vote: VoteKind,
+        rationale: Option<String>,
+    ) -> CanisterResult<ProposalEntry> {
         let (id, proposal) = ProposalStorage::get(id)?;
 
         if proposal.status != Status::Pending {
             return Err(Error::bad_request().add_message("Proposal is not pending"));
         }
 
+        if let Some(rationale) = &rationale {
+            Validator::new(vec![ValidateField(
+                ValidationType::StringLength(rationale.clone(), 0, MAX_RATIONALE_LENGTH),
+                "rationale".to_owned(),
+            )])
+            .validate()?;
+        }
+
         let (_, mut votes) = VoteStorage::get(id)?;
+        let vote = Vote::new(caller, vote, rationale);
+        OwnerLogic::record_activity(caller)?;
 
         match votes.voted(&caller) {
-            true => votes.update(caller, vote),
-            false => votes.add(Vote::new(caller, vote)),
+            true if ConfigLogic::get().lock_votes() => {
+                return Err(Error::bad_request().add_message("Votes can't be changed once cast"));
+            }
+            true => votes.update(vote.clone()),
+            false => votes.add(vote.clone()),
         }
 
         spawn(NotificationLogic::send_update_proposal(id));
         VoteStorage::update(id, votes)?;
+        VoteHistoryStorage::append(id, vote)?;
+        ProposalStorage::get(id)
+    }
+
+    /// Creates each proposal independently, a failing proposal doesn't affect the others.
+    /// The exchange rate of each ledger is fetched once for the whole batch
+    pub async fn propose_many(
+        caller: Principal,
+        inputs: Vec<ProposalInput>,
+    ) -> CanisterResult<Vec<CanisterResult<ProposalEntry>>> {
+        Self::validate_batch_size(inputs.len())?;
+
+        let mut rates = HashMap::new();
+        let mut results = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            results.push(
+                Self::create(
+                    caller,
+                    input.content,
+                    input.voting_period,
+                    input.details,
+                    &mut rates,
+                )
+                .await,
+            );
+        }
+
+        Ok(results)
+    }
+
+    /// Casts each vote independently, a failing vote doesn't affect the others
+    pub fn vote_many(
+        caller: Principal,
+        votes: Vec<(u64, VoteKind, Option<String>)>,
+    ) -> CanisterResult<Vec<CanisterResult<ProposalEntry>>> {
+        Self::validate_batch_size(votes.len())?;
+
+        Ok(votes
+            .into_iter()
+            .map(|(id, vote, rationale)| Self::vote(caller, id, vote, rationale))
+            .collect())
+    }
+
+    fn validate_batch_size(size: usize) -> CanisterResult<()> {
+        Validator::new(vec![ValidateField(
+            ValidationType::Count(size, 1, MAX_BATCH_SIZE),
+            "batch".to_owned(),
+        )])
+        .validate()
+    }
+
+    /// Sets the execution timers of the pending proposals again, timers don't survive upgrades.
+    /// Proposals of which the voting period ended in the meantime are executed right away
+    pub fn start_execution_timers() {
+        for (id, proposal) in ProposalStorage::filter(|_, p| p.status == Status::Pending) {
+            Self::set_execution_timer(id, proposal.deadline());
+        }
+    }
+
+    fn set_execution_timer(id: u64, deadline: u64) {
+        set_timer(
+            Duration::from_nanos(deadline.saturating_sub(time())),
+            move || {
+                spawn(async move {
+                    let _ = Self::execute(id).await;
+                });
+            },
+        );
+    }
+
+    pub async fn execute_overdue(id: u64) -> CanisterResult<ProposalEntry> {
+        let (_, proposal) = ProposalStorage::get(id)?;
+
+        if time() < proposal.deadline() {
+            return Err(Error::bad_request().add_message("Voting period has not ended"));
+        }
+
+        Self::execute(id).await?;
         ProposalStorage::get(id)
     }
 
     async fn execute(id: u64) -> CanisterResult<()> {
+        // A USD limit could have been added after the proposal was created. The rate is fetched
+        // first, so nothing is awaited between the checks below and the transfers
+        let valuation = match ProposalStorage::get(id)? {
+            (_, p) if p.valuation.is_none() && p.status == Status::Pending => {
+                Self::get_valuation(&p.content, &mut HashMap::new()).await?
+            }
+            (_, p) => p.valuation,
+        };
+
         let (_, votes) = VoteStorage::get(id)?;
+        let (_, proposal) = ProposalStorage::get(id)?;
 
-        let (_, proposal) = match Self::get_tally_result(&votes) {
+        if proposal.status != Status::Pending {
+            return Err(Error::bad_request().add_message("Proposal is not pending"));
+        }
+
+        // Stays pending while paused, it can be executed with `execute_proposal` afterwards
+        PauseLogic::check(&proposal.content)?;
+
+        // The ledgers reject a `created_at_time` outside of their transaction window, which can
+        // pass when the execution was delayed, for example by a pause
+        if let Err(err) = Self::check_transfer_window(&proposal.content) {
+            ProposalStorage::expire(id)?;
+            spawn(NotificationLogic::send_decline_proposal(id));
+            return Err(err);
+        }
+
+        // Recipients and spending limits are checked again, the lists could have changed and
+        // transfers executed in the meantime count towards the limits as well. Nothing may be
+        // awaited between this check and the reservation of the spendings by the transfers
+        let threshold = match Self::check_transfers(&proposal.content, valuation.as_ref()) {
+            Ok(threshold) => threshold.max(proposal.threshold),
+            Err(err) => {
+                ProposalStorage::reject(id, false)?;
+                spawn(NotificationLogic::send_decline_proposal(id));
+                return Err(err);
+            }
+        };
+
+        let (_, proposal) = match Self::get_tally_result(&votes, threshold) {
             TallyResult::Approve => ProposalStorage::approve(id),
             TallyResult::Reject => ProposalStorage::reject(id, false),
             TallyResult::Deadlock => ProposalStorage::reject(id, true),
@@ -110,31 +375,136 @@ impl ProposalLogic {
         spawn(NotificationLogic::send_accept_proposal(id));
 
         match proposal.content {
-            Content::Transfer(content) => TransferLogic::execute_transfer(content).await,
-            Content::Airdrop(content) => AirdropLogic::execute_airdrop(id, content).await,
+            Content::Transfer(content) => {
+                TransferLogic::execute_transfer(id, content, valuation).await
+            }
+            Content::Airdrop(content) => {
+                AirdropLogic::execute_airdrop(id, content, valuation).await
+            }
+            Content::SetSpendingLimit(limit) => SpendingLimitLogic::set(limit).map(|_| ()),
+            Content::RemoveSpendingLimit(id) => SpendingLimitLogic::remove(id),
+            Content::UpdateConfig(config) => ConfigLogic::set(config).map(|_| ()),
+            Content::SetAddressBookEntry(content) => AddressBookLogic::set(content).map(|_| ()),
+            Content::RemoveAddressBookEntry(name) => AddressBookLogic::remove(name),
+            Content::AddRecipient(content) => RecipientListLogic::add(content),
+            Content::RemoveRecipient(content) => RecipientListLogic::remove(content),
+            Content::AddMember(member) => WhitelistLogic::add_member(member).map(|_| ()),
+            Content::RemoveMember(member) => WhitelistLogic::remove_member(member).map(|_| ()),
+            Content::ReplaceMember(content) => {
+                WhitelistLogic::replace_member(content.old, content.new).map(|_| ())
+            }
+            Content::UpdateTallyRules(rules) => ConfigLogic::set(Config {
+                tally_rules: Some(rules),
+                ..ConfigLogic::get()
+            })
+            .map(|_| ()),
+            Content::ChangeOwner(owner) => WhitelistLogic::change_owner(owner).map(|_| ()),
+            Content::Unpause => PauseLogic::unpause(),
+            Content::AssignRoles(content) => {
+                RoleLogic::set_roles(content.principal, content.roles).map(|_| ())
+            }
         }
     }
 
-    fn get_tally_result(votes: &Votes) -> TallyResult {
-        let whitelist = WhitelistStorage::get_all();
-        // Plus one for the owner
-        let whitelist_count = (whitelist.len() + 1) as f32;
-        let approval_count = votes.approvals() as f32;
-        let rejection_count = votes.rejections() as f32;
+    fn validate_details(details: &ProposalDetails) -> CanisterResult<()> {
+        let mut fields = vec![
+            ValidateField(
+                ValidationType::StringLength(details.title.clone(), 1, MAX_TITLE_LENGTH),
+                "title".to_owned(),
+            ),
+            ValidateField(
+                ValidationType::Count(details.links.len(), 0, MAX_LINKS),
+                "links".to_owned(),
+            ),
+        ];
 
-        let approval_percentage = (approval_count / whitelist_count) * 100.0;
-        let rejection_percentage = (rejection_count / whitelist_count) * 100.0;
+        if let Some(description) = &details.description {
+            fields.push(ValidateField(
+                ValidationType::StringLength(description.clone(), 0, MAX_DESCRIPTION_LENGTH),
+                "description".to_owned(),
+            ));
+        }
 
-        if approval_percentage > 50.0 {
-            return TallyResult::Approve;
+        for (index, link) in details.links.iter().enumerate() {
+            fields.push(ValidateField(
+                ValidationType::StringLength(link.clone(), 1, MAX_LINK_LENGTH),
+                format!("links[{index}]"),
+            ));
         }
-        if rejection_percentage > 50.0 {
-            return TallyResult::Reject;
+
+        Validator::new(fields).validate()
+    }
+
+    /// USD valuation of the transfers, only fetched when the ledger has USD denominated limits
+    async fn get_valuation(
+        content: &Content,
+        rates: &mut HashMap<Principal, UsdValuation>,
+    ) -> CanisterResult<Option<UsdValuation>> {
+        let Some((canister_id, transfers)) = content.transfers() else {
+            return Ok(None);
+        };
+
+        if !SpendingLimitLogic::has_usd_limits(canister_id) {
+            return Ok(None);
         }
-        if approval_percentage == 50.0 && rejection_percentage == 50.0 {
-            return TallyResult::Deadlock;
+
+        let total = transfers
+            .iter()
+            .fold(Nat::from(0u32), |acc, (_, amount)| acc + amount.clone());
+
+        let rate = match rates.get(&canister_id) {
+            Some(rate) => rate.clone(),
+            None => {
+                let rate = ExchangeRateLogic::get_rate(canister_id).await?;
+                rates.insert(canister_id, rate.clone());
+                rate
+            }
+        };
+
+        Ok(Some(rate.with_amount(&total)))
+    }
+
+    /// Checks whether the ledgers still accept the `created_at_time` of the transfers when
+    /// executed now
+    fn check_transfer_window(content: &Content) -> CanisterResult<()> {
+        match content {
+            Content::Transfer(content) => {
+                TransferLogic::validate_args(std::slice::from_ref(&content.args), 0)
+            }
+            Content::Airdrop(content) => TransferLogic::validate_args(&content.args, 0),
+            _ => Ok(()),
         }
+    }
+
+    /// Checks the recipients of the transfers against the recipient lists
+    /// # Returns
+    /// * `Option<u8>` - The approval threshold required by the spending limits the content would
+    ///   exceed, if any
+    fn check_transfers(
+        content: &Content,
+        valuation: Option<&UsdValuation>,
+    ) -> CanisterResult<Option<u8>> {
+        let Some((canister_id, transfers)) = content.transfers() else {
+            return Ok(None);
+        };
+
+        let recipients = transfers.iter().map(|(to, _)| *to).collect::<Vec<_>>();
+        RecipientListLogic::check(&recipients)?;
+
+        SpendingLimitLogic::check(canister_id, &transfers, valuation)
+    }
+
+    fn get_tally_result(votes: &Votes, threshold: Option<u8>) -> TallyResult {
+        let members = WhitelistLogic::get_whitelist().unwrap_or_default();
+        let config = ConfigLogic::get();
+
+        // Delegates vote for the delegators that didn't vote themselves
+        let votes = votes
+            .of_members(&members)
+            .with_delegations(&DelegationLogic::get_active_pairs().unwrap_or_default());
 
-        TallyResult::NotReached
+        // Pending invitations don't lower the quorum below the minimum amount of members
+        let electorate = members.len().max(config.member_bounds().min as usize);
+        votes.tally(electorate, config.tally_rules.as_ref(), threshold)
     }
 }
@@ -0,0 +1,70 @@
+use icrc_ledger_types::icrc1::account::Account;
+use types::{Error, RecipientList, RecipientListEntry, RecipientListProposalContent};
+
+use crate::{
+    result::CanisterResult,
+    storage::{RecipientListStorage, StorageInsertable, StorageQueryable, StorageUpdateable},
+};
+
+use super::ConfigLogic;
+
+pub struct RecipientListLogic;
+
+impl RecipientListLogic {
+    pub fn get_entries(list: Option<RecipientList>) -> Vec<(u64, RecipientListEntry)> {
+        RecipientListStorage::filter(|_, entry| match &list {
+            Some(list) => &entry.list == list,
+            None => true,
+        })
+    }
+
+    pub fn validate_add(content: &RecipientListProposalContent) -> CanisterResult<()> {
+        if RecipientListStorage::is_listed(&content.account, &content.list) {
+            return Err(Error::duplicate().add_message("Recipient is already on the list"));
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_remove(content: &RecipientListProposalContent) -> CanisterResult<()> {
+        if !RecipientListStorage::is_listed(&content.account, &content.list) {
+            return Err(Error::not_found().add_message("Recipient is not on the list"));
+        }
+
+        Ok(())
+    }
+
+    pub fn add(content: RecipientListProposalContent) -> CanisterResult<()> {
+        Self::validate_add(&content)?;
+        RecipientListStorage::insert(RecipientListEntry::new(content.account, content.list))?;
+        Ok(())
+    }
+
+    pub fn remove(content: RecipientListProposalContent) -> CanisterResult<()> {
+        match RecipientListStorage::find_entry(&content.account, &content.list) {
+            Some((id, _)) => RecipientListStorage::remove(id),
+            None => Err(Error::not_found().add_message("Recipient is not on the list")),
+        }
+    }
+
+    /// Checks that none of the recipients is denied and, when the allowlist is enforced, that
+    /// all of them are allowed
+    pub fn check(recipients: &[Account]) -> CanisterResult<()> {
+        let enforce_allowlist = ConfigLogic::get().enforce_recipient_allowlist();
+
+        for (index, account) in recipients.iter().enumerate() {
+            if RecipientListStorage::is_listed(account, &RecipientList::Deny) {
+                return Err(Error::recipient_not_allowed()
+                    .add_message(&format!("Recipient {index} is on the denylist")));
+            }
+
+            if enforce_allowlist && !RecipientListStorage::is_listed(account, &RecipientList::Allow)
+            {
+                return Err(Error::recipient_not_allowed()
+                    .add_message(&format!("Recipient {index} is not on the allowlist")));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use candid::Principal;
+use types::{Error, Permission, Role, RoleAssignment, Roles};
+
+use crate::{
+    result::CanisterResult,
+    storage::{CellStorage, OwnerStorage, RoleStorage, StorageQueryable, StorageUpdateable},
+};
+
+use super::WhitelistLogic;
+
+pub struct RoleLogic;
+
+impl RoleLogic {
+    /// The owner, the whitelisted members and every principal with assigned roles
+    pub fn get_assignments() -> CanisterResult<Vec<RoleAssignment>> {
+        let mut principals = WhitelistLogic::get_whitelist()?;
+        for (principal, _) in RoleStorage::get_all() {
+            if !principals.contains(&principal) {
+                principals.push(principal);
+            }
+        }
+
+        Ok(principals
+            .into_iter()
+            .map(|principal| RoleAssignment {
+                principal,
+                roles: Self::get_assigned(principal).0,
+                implicit_roles: Self::get_implicit(principal),
+            })
+            .collect())
+    }
+
+    /// The assigned and implicit roles, the voter role only applies to whitelisted members
+    pub fn get_roles(principal: Principal) -> Roles {
+        let is_member = WhitelistLogic::is_member(principal);
+        let mut roles = Self::get_implicit(principal);
+
+        for role in Self::get_assigned(principal).0 {
+            if !roles.contains(&role) && (role != Role::Voter || is_member) {
+                roles.push(role);
+            }
+        }
+
+        Roles(roles)
+    }
+
+    pub fn has_permission(principal: Principal, permission: Permission) -> bool {
+        Self::get_roles(principal).has_permission(permission)
+    }
+
+    /// Replaces the assigned roles of a principal, no roles removes the assignment
+    pub fn assign(
+        caller: Principal,
+        principal: Principal,
+        roles: Vec<Role>,
+    ) -> CanisterResult<RoleAssignment> {
+        Self::validate_assignment(principal, &roles)?;
+
+        // Only the owner can grant or take the admin role, admins can't escalate
+        let changes_admin =
+            roles.contains(&Role::Admin) != Self::get_assigned(principal).0.contains(&Role::Admin);
+        if changes_admin && OwnerStorage::get().ok() != Some(caller) {
+            return Err(Error::unauthorized().add_message("Only the owner can assign admins"));
+        }
+
+        Self::set_roles(principal, roles)
+    }
+
+    pub fn validate_assignment(principal: Principal, roles: &[Role]) -> CanisterResult<()> {
+        if principal == Principal::anonymous() {
+            return Err(Error::bad_request().add_message("Cannot assign roles to anonymous"));
+        }
+
+        if roles.contains(&Role::Voter) && !WhitelistLogic::is_member(principal) {
+            return Err(Error::bad_request().add_message("Only whitelisted members can vote"));
+        }
+
+        Ok(())
+    }
+
+    /// Assigns the roles without checking the caller, used for approved proposals
+    pub fn set_roles(principal: Principal, mut roles: Vec<Role>) -> CanisterResult<RoleAssignment> {
+        roles.sort();
+        roles.dedup();
+
+        match roles.is_empty() {
+            true => {
+                let _ = RoleStorage::remove(principal);
+            }
+            false => {
+                RoleStorage::upsert(principal, Roles(roles.clone()))?;
+            }
+        }
+
+        Ok(RoleAssignment {
+            principal,
+            roles,
+            implicit_roles: Self::get_implicit(principal),
+        })
+    }
+
+    fn get_assigned(principal: Principal) -> Roles {
+        RoleStorage::get_opt(principal)
+            .map(|(_, roles)| roles)
+            .unwrap_or_default()
+    }
+
+    /// The owner holds every role, whitelisted members can vote. Without an owner the members
+    /// can also propose and execute, as the wallet is administered through proposals
+    fn get_implicit(principal: Principal) -> Vec<Role> {
+        let owner = OwnerStorage::get().ok();
+        if owner == Some(principal) {
+            return Role::all();
+        }
+
+        match (WhitelistLogic::is_member(principal), owner) {
+            (true, Some(_)) => vec![Role::Voter],
+            (true, None) => vec![Role::Proposer, Role::Voter, Role::Executor],
+            (false, _) => vec![],
+        }
+    }
+}
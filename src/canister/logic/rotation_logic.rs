@@ -0,0 +1,102 @@
+use candid::Principal;
+use ic_cdk::api::time;
+use types::{Error, Rotation, RotationEntry, Status};
+
+use crate::{
+    result::CanisterResult,
+    storage::{
+        BallotKeyStorage, DelegationStorage, InvitationStorage, ProposalStorage,
+        RecoverySupportStorage, RoleStorage, RotationStorage, StorageQueryable, StorageUpdateable,
+        VoteStorage,
+    },
+};
+
+use super::{DelegationLogic, WhitelistLogic, ROTATION_PERIOD_IN_NANOS};
+
+pub struct RotationLogic;
+
+impl RotationLogic {
+    pub fn get_rotations() -> Vec<RotationEntry> {
+        let now = time();
+        RotationStorage::filter(|_, rotation| rotation.is_active(now))
+    }
+
+    /// Requests to move the membership of the caller to a new principal, replacing an earlier
+    /// request. The new principal has to accept the rotation to prove it is controlled by the member
+    pub fn request(caller: Principal, new: Principal) -> CanisterResult<RotationEntry> {
+        WhitelistLogic::validate_new_member(new)?;
+
+        let now = time();
+        if RotationStorage::find(|member, r| member != &caller && r.new == new && r.is_active(now))
+            .is_some()
+        {
+            return Err(Error::duplicate()
+                .add_message("Principal is already requested by another rotation"));
+        }
+
+        RotationStorage::upsert(
+            caller,
+            Rotation::new(new, time().saturating_add(ROTATION_PERIOD_IN_NANOS)),
+        )
+    }
+
+    pub fn cancel(caller: Principal) -> CanisterResult<()> {
+        RotationStorage::remove(caller)
+    }
+
+    /// Accepts the rotation requested to the caller, the votes on pending proposals, delegations,
+    /// ballot key, assigned roles, recovery support and pending invitation of the member are moved
+    /// to the caller
+    pub fn accept(caller: Principal) -> CanisterResult<Vec<Principal>> {
+        let now = time();
+        let (old, _) = RotationStorage::find(|_, r| r.new == caller && r.is_active(now))
+            .ok_or_else(|| Error::not_found().add_message("No rotation requested"))?;
+
+        let whitelist = WhitelistLogic::rotate(old, caller)?;
+        RotationStorage::remove(old)?;
+
+        for (id, _) in ProposalStorage::filter(|_, p| p.status == Status::Pending) {
+            if let Some((_, mut votes)) = VoteStorage::get_opt(id) {
+                if votes.voted(&old) {
+                    votes.rotate_voter(&old, caller);
+                    VoteStorage::update(id, votes)?;
+                }
+            }
+        }
+
+        for (id, mut delegation) in DelegationLogic::get_delegations(Some(true)) {
+            if delegation.delegator == old || delegation.delegate == old {
+                delegation.rotate(&old, caller);
+                DelegationStorage::update(id, delegation)?;
+            }
+        }
+
+        if let Some((_, key)) = BallotKeyStorage::get_opt(old) {
+            BallotKeyStorage::remove(old)?;
+            BallotKeyStorage::upsert(caller, key)?;
+        }
+
+        // Support given by the member and support for the member as owner candidate
+        for (supporter, candidate) in RecoverySupportStorage::get_all() {
+            if supporter != old && candidate != old {
+                continue;
+            }
+
+            let rotate = |p: Principal| if p == old { caller } else { p };
+            RecoverySupportStorage::remove(supporter)?;
+            RecoverySupportStorage::upsert(rotate(supporter), rotate(candidate))?;
+        }
+
+        if let Some((_, roles)) = RoleStorage::get_opt(old) {
+            RoleStorage::remove(old)?;
+            RoleStorage::upsert(caller, roles)?;
+        }
+
+        if let Some((_, invitation)) = InvitationStorage::get_opt(old) {
+            InvitationStorage::remove(old)?;
+            InvitationStorage::upsert(caller, invitation)?;
+        }
+
+        Ok(whitelist)
+    }
+}
@@ -0,0 +1,167 @@
+use candid::{Nat, Principal};
+use ic_cdk::api::time;
+use icrc_ledger_types::icrc1::account::Account;
+use types::{
+    Denomination, Error, ExceedAction, Spending, SpendingAllowance, SpendingLimit,
+    SpendingLimitEntry, UsdValuation, ValidateField, ValidationType,
+};
+
+use crate::{
+    helpers::validator::Validator,
+    result::CanisterResult,
+    storage::{
+        SpendingLimitStorage, SpendingStorage, StorageInsertable, StorageQueryable,
+        StorageUpdateable,
+    },
+};
+
+pub struct SpendingLimitLogic;
+
+impl SpendingLimitLogic {
+    pub fn get_limits() -> Vec<SpendingLimitEntry> {
+        SpendingLimitStorage::get_all()
+    }
+
+    pub fn get_allowances(canister_id: Option<Principal>) -> Vec<SpendingAllowance> {
+        SpendingLimitStorage::filter(|_, limit| match canister_id {
+            Some(canister_id) => limit.canister_id == canister_id,
+            None => true,
+        })
+        .into_iter()
+        .map(|(id, limit)| Self::get_allowance(id, limit))
+        .collect()
+    }
+
+    pub fn validate(limit: &SpendingLimit) -> CanisterResult<()> {
+        if limit.amount == 0u32 {
+            return Err(Error::bad_request().add_message("Spending limit amount must be positive"));
+        }
+
+        // At least a majority, a lower threshold would make exceeding the limit easier
+        if let ExceedAction::RequireApproval(threshold) = limit.on_exceed {
+            Validator::new(vec![ValidateField(
+                ValidationType::Count(threshold as usize, 51, 100),
+                "on_exceed".to_owned(),
+            )])
+            .validate()?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether proposals for the ledger canister need a USD valuation
+    pub fn has_usd_limits(canister_id: Principal) -> bool {
+        SpendingLimitStorage::find(|_, limit| {
+            limit.canister_id == canister_id && limit.denomination() == Denomination::Usd
+        })
+        .is_some()
+    }
+
+    pub fn exists(id: u64) -> CanisterResult<()> {
+        SpendingLimitStorage::get(id).map(|_| ())
+    }
+
+    pub fn set(limit: SpendingLimit) -> CanisterResult<SpendingLimitEntry> {
+        SpendingLimitStorage::insert(limit)
+    }
+
+    pub fn remove(id: u64) -> CanisterResult<()> {
+        SpendingLimitStorage::remove(id)
+    }
+
+    /// Checks the transfers against the spending limits of the ledger canister
+    /// # Arguments
+    /// * `valuation` - Used to convert the amounts for USD denominated limits
+    /// # Returns
+    /// * `Option<u8>` - The approval threshold required by the exceeded limits, if any
+    /// # Errors
+    /// * `SpendingLimitExceeded` - When a blocking limit would be exceeded
+    pub fn check(
+        canister_id: Principal,
+        transfers: &[(Account, Nat)],
+        valuation: Option<&UsdValuation>,
+    ) -> CanisterResult<Option<u8>> {
+        let mut threshold: Option<u8> = None;
+
+        for (id, limit) in SpendingLimitStorage::filter(|_, l| l.canister_id == canister_id) {
+            let amount = transfers
+                .iter()
+                .filter(|(to, _)| limit.recipient.as_ref().is_none_or(|r| r == to))
+                .fold(Nat::from(0u32), |acc, (_, amount)| acc + amount.clone());
+
+            let amount = match (limit.denomination(), valuation) {
+                (Denomination::Token, _) => amount,
+                (Denomination::Usd, Some(valuation)) => valuation.to_usd_cents(&amount),
+                (Denomination::Usd, None) => {
+                    return Err(Error::internal()
+                        .add_message(&format!("No USD valuation for spending limit {id}")))
+                }
+            };
+
+            if amount == 0u32 {
+                continue;
+            }
+
+            let allowance = Self::get_allowance(id, limit);
+            if amount <= allowance.remaining {
+                continue;
+            }
+
+            match allowance.limit.on_exceed {
+                ExceedAction::Block => {
+                    return Err(Error::spending_limit_exceeded()
+                        .add_message(&format!("Spending limit {id} exceeded")))
+                }
+                ExceedAction::RequireApproval(required) => {
+                    threshold = threshold.max(Some(required));
+                }
+            }
+        }
+
+        Ok(threshold)
+    }
+
+    /// Records a spending before its transfer is sent, so executions running at the same time
+    /// count each other's transfers towards the limits
+    /// # Returns
+    /// * `u64` - The ID of the spending, to release it when the transfer fails
+    pub fn reserve(
+        proposal_id: u64,
+        canister_id: Principal,
+        to: Account,
+        amount: Nat,
+        valuation: Option<&UsdValuation>,
+    ) -> CanisterResult<u64> {
+        let usd_value = valuation.map(|valuation| valuation.to_usd_cents(&amount));
+        let (id, _) = SpendingStorage::insert(Spending::new(
+            proposal_id,
+            canister_id,
+            to,
+            amount,
+            usd_value,
+        ))?;
+        Ok(id)
+    }
+
+    pub fn release(id: u64) -> CanisterResult<()> {
+        SpendingStorage::remove(id)
+    }
+
+    fn get_allowance(id: u64, limit: SpendingLimit) -> SpendingAllowance {
+        let window_start = limit.window_start(time());
+        let consumed = SpendingStorage::consumed(&limit, window_start);
+
+        let remaining = match consumed < limit.amount {
+            true => limit.amount.clone() - consumed.clone(),
+            false => Nat::from(0u32),
+        };
+
+        SpendingAllowance {
+            id,
+            limit,
+            consumed,
+            remaining,
+            window_start,
+        }
+    }
+}
@@ -1,28 +1,123 @@
 use candid::{Nat, Principal};
-use ic_cdk::id;
+use ic_cdk::{api::time, id};
 use icrc_ledger_types::icrc1::{account::Account, transfer::TransferArg};
 
-use types::{Error, TransferProposalContent};
+use types::{Error, TransferProposalContent, UsdValuation, ValidateField, ValidationType};
 
 use crate::{
-    helpers::icrc::{icrc1_balance_of, icrc1_transfer},
+    helpers::{
+        icrc::{icrc1_balance_of, icrc1_transfer, ICRC1TransferResult},
+        validator::Validator,
+    },
     result::CanisterResult,
 };
 
+use super::{
+    SpendingLimitLogic, MAX_MEMO_LENGTH, PERMITTED_DRIFT_IN_NANOS, TRANSACTION_WINDOW_IN_NANOS,
+};
+
 pub struct TransferLogic;
 
 impl TransferLogic {
-    pub async fn transfer(canister_id: Principal, args: TransferArg) -> CanisterResult<()> {
+    /// Sends a transfer of which the spending is reserved, the reservation is released when the
+    /// transfer fails
+    pub async fn transfer(
+        canister_id: Principal,
+        args: TransferArg,
+        reservation: u64,
+    ) -> CanisterResult<()> {
+        let result = Self::send(canister_id, args).await;
+        if result.is_err() {
+            SpendingLimitLogic::release(reservation)?;
+        }
+
+        result
+    }
+
+    /// The spending is reserved before the first await, right after the spending limits were
+    /// checked by the proposal execution
+    pub async fn execute_transfer(
+        proposal_id: u64,
+        content: TransferProposalContent,
+        valuation: Option<UsdValuation>,
+    ) -> CanisterResult<()> {
+        let reservation = SpendingLimitLogic::reserve(
+            proposal_id,
+            content.canister_id,
+            content.args.to,
+            content.args.amount.clone(),
+            valuation.as_ref(),
+        )?;
+
+        Self::transfer(content.canister_id, content.args, reservation).await
+    }
+
+    async fn send(canister_id: Principal, args: TransferArg) -> CanisterResult<()> {
         Self::check_balance(canister_id, &args.amount).await?;
 
-        icrc1_transfer(canister_id, args)
+        let (result,) = icrc1_transfer(canister_id, args)
             .await
             .map_err(|(_, e)| Error::internal().add_message(&format!("transfer failed: {e}")))?;
+
+        if let ICRC1TransferResult::Err(e) = result {
+            return Err(Error::internal().add_message(&format!("transfer failed: {e}")));
+        }
+
         Ok(())
     }
 
-    pub async fn execute_transfer(content: TransferProposalContent) -> CanisterResult<()> {
-        Self::transfer(content.canister_id, content.args).await
+    /// Validates the transfer arguments of a proposal, the errors are reported per index
+    /// # Arguments
+    /// * `voting_period` - The transfers are executed after the voting period, so the
+    ///   `created_at_time` has to be valid at that moment
+    pub fn validate_args(args: &[TransferArg], voting_period: u64) -> CanisterResult<()> {
+        let executed_at = time().saturating_add(voting_period);
+        let excluded = vec![Principal::anonymous(), id()];
+        let receivers = args
+            .iter()
+            .map(|arg| arg.to.to_string())
+            .collect::<Vec<_>>();
+
+        let mut fields = vec![];
+
+        for (index, arg) in args.iter().enumerate() {
+            fields.push(ValidateField(
+                ValidationType::NonZero(arg.amount.clone()),
+                format!("args[{index}].amount"),
+            ));
+
+            fields.push(ValidateField(
+                ValidationType::ExcludedPrincipal(arg.to.owner, excluded.clone()),
+                format!("args[{index}].to"),
+            ));
+
+            fields.push(ValidateField(
+                ValidationType::Unique(receivers[index].clone(), receivers[..index].to_vec()),
+                format!("args[{index}].to"),
+            ));
+
+            if let Some(memo) = &arg.memo {
+                fields.push(ValidateField(
+                    ValidationType::Count(memo.0.len(), 0, MAX_MEMO_LENGTH),
+                    format!("args[{index}].memo"),
+                ));
+            }
+
+            if let Some(created_at_time) = arg.created_at_time {
+                fields.push(ValidateField(
+                    ValidationType::Range(
+                        created_at_time,
+                        executed_at
+                            .saturating_sub(TRANSACTION_WINDOW_IN_NANOS)
+                            .saturating_sub(PERMITTED_DRIFT_IN_NANOS),
+                        executed_at.saturating_add(PERMITTED_DRIFT_IN_NANOS),
+                    ),
+                    format!("args[{index}].created_at_time"),
+                ));
+            }
+        }
+
+        Validator::new(fields).validate()
     }
 
     pub async fn check_balance(ledger_canister: Principal, amount: &Nat) -> CanisterResult<()> {
@@ -1,22 +1,28 @@
 use std::time::Duration;
 
 use candid::Principal;
-use ic_cdk::{spawn, trap};
+use ic_cdk::{api::time, spawn, trap};
 use ic_cdk_timers::set_timer;
-use types::{Error, ValidateField, ValidationType};
+use types::{Error, Invitation, InvitationEntry, MemberBounds, ValidateField, ValidationType};
 
 use crate::{
     helpers::validator::Validator,
     result::CanisterResult,
-    storage::{CellStorage, OwnerStorage, StorageInsertable, StorageQueryable, WhitelistStorage},
+    storage::{
+        CellStorage, InvitationStorage, OwnerStorage, StorageInsertable, StorageQueryable,
+        StorageUpdateable, WhitelistStorage,
+    },
 };
 
-use super::{notifications_logic::NotificationLogic, MAX_WHITELISTED, MIN_WHITELISTED};
+use super::{
+    notifications_logic::NotificationLogic, ConfigLogic, OwnerLogic, INVITATION_PERIOD_IN_NANOS,
+};
 
 pub struct WhitelistLogic;
 
 impl WhitelistLogic {
-    pub fn init(owner: Principal, whitelisted: Vec<Principal>) {
+    /// Without an owner the wallet is only administered through proposals
+    pub fn init(owner: Option<Principal>, whitelisted: Vec<Principal>) {
         let mut deduped = whitelisted.clone();
         deduped.sort();
         deduped.dedup();
@@ -28,41 +34,74 @@ impl WhitelistLogic {
         // Filter out the owner from the whitelisted to ensure amount is correct
         let whitelisted = whitelisted
             .into_iter()
-            .filter(|p| p != &owner)
+            .filter(|p| Some(*p) != owner)
             .collect::<Vec<_>>();
 
         // Plus one for the owner
-        let whitelisted_size = whitelisted.len() + 1;
+        let whitelisted_size = whitelisted.len() + usize::from(owner.is_some());
+        let MemberBounds { min, max } = ConfigLogic::get().member_bounds();
 
-        if whitelisted_size < MIN_WHITELISTED {
-            trap(&format!(
-                "At least {MIN_WHITELISTED} principals must be whitelisted."
-            ));
+        if whitelisted_size < min as usize {
+            trap(&format!("At least {min} principals must be whitelisted."));
         }
-        if whitelisted_size > MAX_WHITELISTED {
-            trap(&format!(
-                "At most {MAX_WHITELISTED} principals can be whitelisted."
-            ));
+        if whitelisted_size > max as usize {
+            trap(&format!("At most {max} principals can be whitelisted."));
         }
 
-        OwnerStorage::set(owner).expect("Failed to set owner");
+        if let Some(owner) = owner {
+            OwnerLogic::set(owner).expect("Failed to set owner");
+        }
 
-        for principal in whitelisted {
-            WhitelistStorage::insert(principal).expect("Failed to insert principal");
+        for principal in whitelisted.iter() {
+            WhitelistStorage::insert(*principal).expect("Failed to insert principal");
+            Self::invite(*principal).expect("Failed to invite principal");
         }
 
-        set_timer(Duration::from_secs(1), || {
-            spawn(NotificationLogic::send_whitelist_notice())
+        // Calls can't be made during the installation. The invitees are notified separately, as
+        // the whitelist only holds the members that accepted
+        set_timer(Duration::from_secs(1), move || {
+            spawn(NotificationLogic::send_whitelist_notice());
+            for principal in whitelisted {
+                spawn(NotificationLogic::send_member_invited(principal));
+            }
         });
     }
 
+    /// The members that count in the electorate, invited principals are excluded until they
+    /// accepted their invitation
     pub fn get_whitelist() -> CanisterResult<Vec<Principal>> {
-        let result = vec![OwnerStorage::get()?];
-        let whitelisted = WhitelistStorage::get_all().into_iter().map(|(_, v)| v);
-        Ok(result.into_iter().chain(whitelisted).collect())
+        let mut whitelist = Self::get_seats()?;
+        whitelist.retain(|p| !InvitationStorage::contains_key(*p));
+        Ok(whitelist)
+    }
+
+    pub fn is_member(principal: Principal) -> bool {
+        Self::get_whitelist().is_ok_and(|whitelist| whitelist.contains(&principal))
+    }
+
+    pub fn get_invitations() -> Vec<InvitationEntry> {
+        InvitationStorage::get_all()
     }
 
+    pub fn accept_invitation(caller: Principal) -> CanisterResult<Vec<Principal>> {
+        let (_, invitation) = InvitationStorage::get(caller)
+            .map_err(|_| Error::not_found().add_message("No invitation found"))?;
+
+        if !invitation.is_active(time()) {
+            Self::expire_invitation(caller);
+            return Err(Error::bad_request().add_message("Invitation expired"));
+        }
+
+        InvitationStorage::remove(caller)?;
+
+        spawn(NotificationLogic::send_member_joined(caller));
+        Self::get_whitelist()
+    }
+
+    /// Replaces the whitelisted principals, the records of the principals that stay are kept
     pub fn replace_whitelisted(whitelisted: Vec<Principal>) -> CanisterResult<Vec<Principal>> {
+        Self::validate_direct_change()?;
+
         let mut deduped = whitelisted.clone();
         deduped.sort();
         deduped.dedup();
@@ -71,37 +110,282 @@ impl WhitelistLogic {
             return Err(Error::bad_request().add_message("Duplicate principals in whitelist"));
         }
 
-        Validator::new(vec![ValidateField(
-            ValidationType::Count(
-                whitelisted.len(),
-                // Only whitelisted principals could be replaced, not the owner
-                MIN_WHITELISTED - 1,
-                MAX_WHITELISTED - 1,
-            ),
-            "whitelisted".to_owned(),
-        )])
-        .validate()?;
+        // Plus one for the owner, only whitelisted principals could be replaced
+        Self::validate_member_count(whitelisted.len() + usize::from(OwnerStorage::get().is_ok()))?;
 
         if whitelisted.clone().contains(&Principal::anonymous()) {
             return Err(Error::bad_request().add_message("Cannot replace with anonymous principal"));
         }
 
-        let owner = OwnerStorage::get()?;
-        let owner = whitelisted.clone().into_iter().find(|p| p == &owner);
+        let owner = OwnerStorage::get().ok();
+        let owner = whitelisted.clone().into_iter().find(|p| Some(*p) == owner);
 
         if let Some(owner) = owner {
             return Err(Error::bad_request()
                 .add_message(&format!("Cannot replace owner principal: {owner}")));
         }
 
-        let whitelisted = WhitelistStorage::replace(whitelisted)?;
-        let whitelisted = vec![OwnerStorage::get()?]
+        let current = WhitelistStorage::get_all()
             .into_iter()
-            .chain(whitelisted)
-            .collect();
+            .map(|(_, p)| p)
+            .collect::<Vec<_>>();
+
+        for member in current.iter().filter(|p| !whitelisted.contains(p)) {
+            Self::remove_seat(*member)?;
+            spawn(NotificationLogic::send_member_left(*member));
+        }
+
+        for member in whitelisted.iter().filter(|p| !current.contains(p)) {
+            WhitelistStorage::insert(*member)?;
+            Self::invite(*member)?;
+            spawn(NotificationLogic::send_member_invited(*member));
+        }
+
+        Self::get_whitelist()
+    }
+
+    pub fn add_member_directly(member: Principal) -> CanisterResult<Vec<Principal>> {
+        Self::validate_direct_change()?;
+        Self::add_member(member)
+    }
+
+    pub fn remove_member_directly(member: Principal) -> CanisterResult<Vec<Principal>> {
+        Self::validate_direct_change()?;
+        Self::remove_member(member)
+    }
+
+    pub fn swap_member_directly(old: Principal, new: Principal) -> CanisterResult<Vec<Principal>> {
+        Self::validate_direct_change()?;
+        Self::replace_member(old, new)
+    }
+
+    pub fn validate_add(member: Principal) -> CanisterResult<()> {
+        Self::validate_new_member(member)?;
+        Self::validate_member_count(Self::get_seats()?.len() + 1)
+    }
+
+    pub fn validate_remove(member: Principal) -> CanisterResult<()> {
+        Self::validate_existing_member(member)?;
+        Self::validate_member_count(Self::get_seats()?.len().saturating_sub(1))
+    }
+
+    pub fn validate_replace(old: Principal, new: Principal) -> CanisterResult<()> {
+        Self::validate_existing_member(old)?;
+        Self::validate_new_member(new)
+    }
+
+    pub fn validate_owner_change(new_owner: Principal) -> CanisterResult<()> {
+        if OwnerStorage::get().is_err() {
+            return Err(Error::unsupported().add_message("The wallet has no owner"));
+        }
+
+        Self::validate_existing_member(new_owner)?;
+
+        if InvitationStorage::contains_key(new_owner) {
+            return Err(
+                Error::bad_request().add_message("Principal has not accepted the invitation")
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn add_member(member: Principal) -> CanisterResult<Vec<Principal>> {
+        Self::validate_add(member)?;
+        WhitelistStorage::insert(member)?;
+        Self::invite(member)?;
+
+        spawn(NotificationLogic::send_member_invited(member));
+        Self::get_whitelist()
+    }
+
+    pub fn remove_member(member: Principal) -> CanisterResult<Vec<Principal>> {
+        Self::validate_remove(member)?;
+        Self::remove_seat(member)?;
+
+        spawn(NotificationLogic::send_member_left(member));
+        Self::get_whitelist()
+    }
+
+    /// Replaces the member in place, the record of the member keeps its id
+    pub fn replace_member(old: Principal, new: Principal) -> CanisterResult<Vec<Principal>> {
+        Self::validate_replace(old, new)?;
+        let id = Self::find_id(old)?;
+        WhitelistStorage::update(id, new)?;
+        let _ = InvitationStorage::remove(old);
+        Self::invite(new)?;
+
+        spawn(NotificationLogic::send_member_left(old));
+        spawn(NotificationLogic::send_member_invited(new));
+        Self::get_whitelist()
+    }
+
+    /// Makes a member the owner, the current owner takes the place of the member in the whitelist
+    pub fn change_owner(new_owner: Principal) -> CanisterResult<Vec<Principal>> {
+        Self::validate_owner_change(new_owner)?;
+        let id = Self::find_id(new_owner)?;
+        WhitelistStorage::update(id, OwnerStorage::get()?)?;
+        OwnerStorage::set(new_owner)?;
+
+        spawn(NotificationLogic::send_whitelist_notice());
+        Self::get_whitelist()
+    }
+
+    /// Makes a member the owner in place of an owner that lost access, the old owner doesn't
+    /// keep a seat. The whitelist can drop below its minimum size, otherwise a small wallet
+    /// couldn't recover at all
+    pub fn recover_owner(new_owner: Principal) -> CanisterResult<Vec<Principal>> {
+        Self::validate_owner_change(new_owner)?;
+        let old_owner = OwnerStorage::get()?;
+        WhitelistStorage::remove_by_value(&new_owner)?;
+        OwnerStorage::set(new_owner)?;
+
+        spawn(NotificationLogic::send_member_left(old_owner));
+        spawn(NotificationLogic::send_whitelist_notice());
+        Self::get_whitelist()
+    }
+
+    /// Moves a member, the owner included, to a new principal
+    pub fn rotate(old: Principal, new: Principal) -> CanisterResult<Vec<Principal>> {
+        Self::validate_new_member(new)?;
+
+        // The new principal proved to be controlled by the member, so no invitation is needed
+        match OwnerStorage::get().is_ok_and(|owner| owner == old) {
+            true => {
+                OwnerStorage::set(new)?;
+            }
+            false => {
+                let id = Self::find_id(old)?;
+                WhitelistStorage::update(id, new)?;
+            }
+        }
 
         spawn(NotificationLogic::send_whitelist_notice());
+        Self::get_whitelist()
+    }
 
-        Ok(whitelisted)
+    /// The owner, if any, and all whitelisted principals, invited principals included
+    fn get_seats() -> CanisterResult<Vec<Principal>> {
+        let owner = OwnerStorage::get().ok();
+        let whitelisted = WhitelistStorage::get_all().into_iter().map(|(_, v)| v);
+        Ok(owner.into_iter().chain(whitelisted).collect())
+    }
+
+    fn remove_seat(member: Principal) -> CanisterResult<()> {
+        WhitelistStorage::remove_by_value(&member)?;
+        // Not every member has a pending invitation
+        let _ = InvitationStorage::remove(member);
+        Ok(())
+    }
+
+    /// Sets the expiry timers of the pending invitations again, timers don't survive upgrades
+    pub fn start_invitation_timers() {
+        for (member, invitation) in InvitationStorage::get_all() {
+            Self::set_expiry_timer(member, invitation.expires_at);
+        }
+    }
+
+    fn invite(member: Principal) -> CanisterResult<()> {
+        let expires_at = time().saturating_add(INVITATION_PERIOD_IN_NANOS);
+        InvitationStorage::upsert(member, Invitation::new(expires_at))?;
+        Self::set_expiry_timer(member, expires_at);
+        Ok(())
+    }
+
+    fn set_expiry_timer(member: Principal, expires_at: u64) {
+        set_timer(
+            Duration::from_nanos(expires_at.saturating_sub(time())),
+            move || Self::expire_invitation(member),
+        );
+    }
+
+    /// Frees the seat of a principal that didn't accept the invitation in time, unless the
+    /// whitelist would drop below the minimum. The seat can then still be replaced by proposal
+    fn expire_invitation(member: Principal) {
+        let expired = InvitationStorage::get_opt(member)
+            .is_some_and(|(_, invitation)| !invitation.is_active(time()));
+
+        if !expired {
+            return;
+        }
+
+        let remaining = Self::get_seats().map(|seats| seats.len().saturating_sub(1));
+        if remaining.and_then(Self::validate_member_count).is_err() {
+            return;
+        }
+
+        if Self::remove_seat(member).is_ok() {
+            spawn(NotificationLogic::send_member_left(member));
+        }
+    }
+
+    fn find_id(member: Principal) -> CanisterResult<u64> {
+        WhitelistStorage::find(|_, p| p == &member)
+            .map(|(id, _)| id)
+            .ok_or_else(|| Error::not_found().add_message("Principal is not whitelisted"))
+    }
+
+    /// A whitelisted principal other than the owner
+    fn validate_existing_member(member: Principal) -> CanisterResult<()> {
+        if OwnerStorage::get().is_ok_and(|owner| owner == member) {
+            return Err(Error::bad_request().add_message("Principal is the owner"));
+        }
+
+        Self::find_id(member).map(|_| ())
+    }
+
+    pub fn validate_new_member(member: Principal) -> CanisterResult<()> {
+        if member == Principal::anonymous() {
+            return Err(Error::bad_request().add_message("Cannot add anonymous principal"));
+        }
+
+        if Self::get_seats()?.contains(&member) {
+            return Err(Error::duplicate().add_message("Principal is already whitelisted"));
+        }
+
+        if ConfigLogic::get().guardian == Some(member) {
+            return Err(Error::bad_request().add_message("The guardian cannot be whitelisted"));
+        }
+
+        Ok(())
+    }
+
+    /// The guardian can't be the owner or whitelisted, it would be able to vote otherwise
+    pub fn validate_guardian(guardian: Option<Principal>) -> CanisterResult<()> {
+        let Some(guardian) = guardian else {
+            return Ok(());
+        };
+
+        if guardian == Principal::anonymous() {
+            return Err(Error::bad_request().add_message("The guardian cannot be anonymous"));
+        }
+
+        if Self::get_seats()?.contains(&guardian) {
+            return Err(Error::bad_request().add_message("The guardian cannot be whitelisted"));
+        }
+
+        Ok(())
+    }
+
+    fn validate_direct_change() -> CanisterResult<()> {
+        if ConfigLogic::get().membership_by_proposal_only() {
+            return Err(
+                Error::unsupported().add_message("Members can only be changed through proposals")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// # Arguments
+    /// * `count` - The amount of members including the owner
+    fn validate_member_count(count: usize) -> CanisterResult<()> {
+        let bounds = ConfigLogic::get().member_bounds();
+
+        Validator::new(vec![ValidateField(
+            ValidationType::Count(count, bounds.min as usize, bounds.max as usize),
+            "whitelisted".to_owned(),
+        )])
+        .validate()
     }
 }
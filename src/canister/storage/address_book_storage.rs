@@ -0,0 +1,33 @@
+use ic_stable_structures::memory_manager::MemoryId;
+use icrc_ledger_types::icrc1::account::Account;
+
+use types::AddressBookEntry;
+
+use super::{
+    StaticStorageRef, Storage, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+    ADDRESS_BOOK, ADDRESS_BOOK_MEMORY_ID,
+};
+
+pub struct AddressBookStorage;
+
+impl Storage<String, AddressBookEntry> for AddressBookStorage {
+    const NAME: &'static str = "address_book";
+
+    fn storage() -> StaticStorageRef<String, AddressBookEntry> {
+        &ADDRESS_BOOK
+    }
+
+    fn memory_id() -> MemoryId {
+        ADDRESS_BOOK_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<String, AddressBookEntry> for AddressBookStorage {}
+impl StorageInsertableByKey<String, AddressBookEntry> for AddressBookStorage {}
+impl StorageUpdateable<String, AddressBookEntry> for AddressBookStorage {}
+
+impl AddressBookStorage {
+    pub fn find_name(account: &Account) -> Option<String> {
+        Self::find(|_, entry| entry.address.matches(account)).map(|(name, _)| name)
+    }
+}
@@ -0,0 +1,34 @@
+use candid::Principal;
+use ic_stable_structures::memory_manager::MemoryId;
+
+use types::{BallotKey, SignatureScheme};
+
+use super::{
+    StaticStorageRef, Storage, StorageInsertableByKey, StorageQueryable, StorageUpdateable,
+    BALLOT_KEYS, BALLOT_KEYS_MEMORY_ID,
+};
+
+pub struct BallotKeyStorage;
+
+impl Storage<Principal, BallotKey> for BallotKeyStorage {
+    const NAME: &'static str = "ballot_keys";
+
+    fn storage() -> StaticStorageRef<Principal, BallotKey> {
+        &BALLOT_KEYS
+    }
+
+    fn memory_id() -> MemoryId {
+        BALLOT_KEYS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<Principal, BallotKey> for BallotKeyStorage {}
+impl StorageInsertableByKey<Principal, BallotKey> for BallotKeyStorage {}
+impl StorageUpdateable<Principal, BallotKey> for BallotKeyStorage {}
+
+impl BallotKeyStorage {
+    pub fn find_owner(scheme: &SignatureScheme, public_key: &[u8]) -> Option<Principal> {
+        Self::find(|_, key| &key.scheme == scheme && key.public_key == public_key)
+            .map(|(principal, _)| principal)
+    }
+}
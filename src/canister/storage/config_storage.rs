@@ -0,0 +1,18 @@
+use ic_stable_structures::memory_manager::MemoryId;
+use types::Config;
+
+use super::{CellStorage, CellStorageRef, CONFIG, CONFIG_MEMORY_ID};
+
+pub struct ConfigStorage;
+
+impl CellStorage<Config> for ConfigStorage {
+    const NAME: &'static str = "config";
+
+    fn storage() -> CellStorageRef<Config> {
+        &CONFIG
+    }
+
+    fn memory_id() -> MemoryId {
+        CONFIG_MEMORY_ID
+    }
+}
@@ -0,0 +1,26 @@
+use ic_stable_structures::memory_manager::MemoryId;
+
+use types::Delegation;
+
+use super::{
+    StaticStorageRef, Storage, StorageInsertable, StorageQueryable, StorageUpdateable, DELEGATIONS,
+    DELEGATIONS_MEMORY_ID,
+};
+
+pub struct DelegationStorage;
+
+impl Storage<u64, Delegation> for DelegationStorage {
+    const NAME: &'static str = "delegations";
+
+    fn storage() -> StaticStorageRef<u64, Delegation> {
+        &DELEGATIONS
+    }
+
+    fn memory_id() -> MemoryId {
+        DELEGATIONS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, Delegation> for DelegationStorage {}
+impl StorageInsertable<Delegation> for DelegationStorage {}
+impl StorageUpdateable<u64, Delegation> for DelegationStorage {}
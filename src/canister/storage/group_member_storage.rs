@@ -0,0 +1,26 @@
+use candid::Principal;
+use ic_stable_structures::memory_manager::MemoryId;
+
+use types::GroupMember;
+
+use super::{
+    StaticStorageRef, Storage, StorageQueryable, StorageUpdateable, GROUP_MEMBERS,
+    GROUP_MEMBERS_MEMORY_ID,
+};
+
+pub struct GroupMemberStorage;
+
+impl Storage<Principal, GroupMember> for GroupMemberStorage {
+    const NAME: &'static str = "group_members";
+
+    fn storage() -> StaticStorageRef<Principal, GroupMember> {
+        &GROUP_MEMBERS
+    }
+
+    fn memory_id() -> MemoryId {
+        GROUP_MEMBERS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<Principal, GroupMember> for GroupMemberStorage {}
+impl StorageUpdateable<Principal, GroupMember> for GroupMemberStorage {}
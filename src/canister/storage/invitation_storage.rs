@@ -0,0 +1,26 @@
+use candid::Principal;
+use ic_stable_structures::memory_manager::MemoryId;
+
+use types::Invitation;
+
+use super::{
+    StaticStorageRef, Storage, StorageQueryable, StorageUpdateable, INVITATIONS,
+    INVITATIONS_MEMORY_ID,
+};
+
+pub struct InvitationStorage;
+
+impl Storage<Principal, Invitation> for InvitationStorage {
+    const NAME: &'static str = "invitations";
+
+    fn storage() -> StaticStorageRef<Principal, Invitation> {
+        &INVITATIONS
+    }
+
+    fn memory_id() -> MemoryId {
+        INVITATIONS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<Principal, Invitation> for InvitationStorage {}
+impl StorageUpdateable<Principal, Invitation> for InvitationStorage {}
@@ -1,18 +1,46 @@
+pub mod address_book_storage;
 pub mod airdrop_transfer_storage;
+pub mod ballot_key_storage;
 pub mod cell_api;
+pub mod config_storage;
+pub mod delegation_storage;
+pub mod group_member_storage;
+pub mod invitation_storage;
 pub mod metadata_storage;
 pub mod owner_storage;
+pub mod ownership_storage;
+pub mod pause_storage;
 pub mod proposal_storage;
+pub mod recipient_list_storage;
+pub mod role_storage;
+pub mod rotation_storage;
+pub mod spending_limit_storage;
+pub mod spending_storage;
 pub mod state;
 pub mod storage_api;
+pub mod vote_history_storage;
 pub mod vote_storage;
 pub mod whitelist_storage;
 
+pub use address_book_storage::AddressBookStorage;
 pub use airdrop_transfer_storage::AirdropTransferStorage;
+pub use ballot_key_storage::BallotKeyStorage;
 pub use cell_api::*;
+pub use config_storage::ConfigStorage;
+pub use delegation_storage::DelegationStorage;
+pub use group_member_storage::GroupMemberStorage;
+pub use invitation_storage::InvitationStorage;
 pub use owner_storage::OwnerStorage;
+pub use ownership_storage::{OwnershipStorage, RecoverySupportStorage};
+pub use pause_storage::PauseStorage;
 pub use proposal_storage::ProposalStorage;
+pub use recipient_list_storage::RecipientListStorage;
+pub use role_storage::RoleStorage;
+pub use rotation_storage::RotationStorage;
+pub use spending_limit_storage::SpendingLimitStorage;
+pub use spending_storage::SpendingStorage;
 pub use state::*;
 pub use storage_api::*;
+pub use vote_history_storage::VoteHistoryStorage;
 pub use vote_storage::VoteStorage;
 pub use whitelist_storage::WhitelistStorage;
@@ -0,0 +1,39 @@
+use candid::Principal;
+use ic_stable_structures::memory_manager::MemoryId;
+use types::Ownership;
+
+use super::{
+    CellStorage, CellStorageRef, StaticStorageRef, Storage, StorageQueryable, StorageUpdateable,
+    OWNERSHIP, OWNERSHIP_MEMORY_ID, RECOVERY_SUPPORT, RECOVERY_SUPPORT_MEMORY_ID,
+};
+
+pub struct OwnershipStorage;
+
+impl CellStorage<Ownership> for OwnershipStorage {
+    const NAME: &'static str = "ownership";
+
+    fn storage() -> CellStorageRef<Ownership> {
+        &OWNERSHIP
+    }
+
+    fn memory_id() -> MemoryId {
+        OWNERSHIP_MEMORY_ID
+    }
+}
+
+pub struct RecoverySupportStorage;
+
+impl Storage<Principal, Principal> for RecoverySupportStorage {
+    const NAME: &'static str = "recovery_support";
+
+    fn storage() -> StaticStorageRef<Principal, Principal> {
+        &RECOVERY_SUPPORT
+    }
+
+    fn memory_id() -> MemoryId {
+        RECOVERY_SUPPORT_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<Principal, Principal> for RecoverySupportStorage {}
+impl StorageUpdateable<Principal, Principal> for RecoverySupportStorage {}
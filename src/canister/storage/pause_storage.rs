@@ -0,0 +1,18 @@
+use ic_stable_structures::memory_manager::MemoryId;
+use types::PauseStatus;
+
+use super::{CellStorage, CellStorageRef, PAUSE, PAUSE_MEMORY_ID};
+
+pub struct PauseStorage;
+
+impl CellStorage<PauseStatus> for PauseStorage {
+    const NAME: &'static str = "pause";
+
+    fn storage() -> CellStorageRef<PauseStatus> {
+        &PAUSE
+    }
+
+    fn memory_id() -> MemoryId {
+        PAUSE_MEMORY_ID
+    }
+}
@@ -1,11 +1,12 @@
+use candid::Principal;
 use ic_stable_structures::memory_manager::MemoryId;
-use types::{Proposal, ProposalEntry, ProposalResponse, Status, Votes};
+use types::{Proposal, ProposalEntry, ProposalResponse, RecipientName, Status, Votes};
 
 use crate::result::CanisterResult;
 
 use super::{
-    StaticStorageRef, Storage, StorageInsertable, StorageQueryable, StorageUpdateable, VoteStorage,
-    PROPOSALS, PROPOSALS_MEMORY_ID,
+    AddressBookStorage, StaticStorageRef, Storage, StorageInsertable, StorageQueryable,
+    StorageUpdateable, VoteStorage, PROPOSALS, PROPOSALS_MEMORY_ID,
 };
 
 pub struct ProposalStorage;
@@ -43,6 +44,12 @@ impl ProposalStorage {
         Self::update_status(id, Status::Expired)
     }
 
+    pub fn veto(id: u64, reason: String, vetoed_at: u64) -> CanisterResult<ProposalEntry> {
+        let (_, mut proposal) = Self::get(id)?;
+        proposal.veto(reason, vetoed_at);
+        Self::update(id, proposal)
+    }
+
     pub fn set_sent_at(id: u64, sent_at: u64) -> CanisterResult<ProposalEntry> {
         let (_, mut proposal) = Self::get(id)?;
         proposal.set_sent_at(sent_at);
@@ -58,10 +65,53 @@ impl ProposalStorage {
             true
         });
 
-        proposals.sort_by(|(_, a), (_, b)| a.created_at.cmp(&b.created_at));
+        proposals.sort_by_key(|(_, proposal)| proposal.created_at);
+        proposals.into_iter().map(Self::map_to_response).collect()
+    }
+
+    /// Find the proposals of which the details contain all the terms
+    pub fn search(terms: Vec<String>, status: Option<Status>) -> Vec<ProposalResponse> {
+        let mut proposals = Self::filter(|_, proposal| {
+            if let Some(status) = status.clone() {
+                if proposal.status() != status {
+                    return false;
+                }
+            }
+
+            match &proposal.details {
+                Some(details) => details.matches(&terms),
+                None => false,
+            }
+        });
+
+        proposals.sort_by_key(|(_, proposal)| proposal.created_at);
         proposals.into_iter().map(Self::map_to_response).collect()
     }
 
+    /// The pending proposals the voter has not voted on, sorted by deadline
+    pub fn get_awaiting_vote(voter: Principal) -> Vec<ProposalResponse> {
+        let mut proposals = Self::filter(|id, proposal| {
+            proposal.status() == Status::Pending
+                && !VoteStorage::get_opt(*id).is_some_and(|(_, votes)| votes.voted(&voter))
+        });
+
+        proposals.sort_by_key(|(_, proposal)| proposal.deadline());
+        proposals.into_iter().map(Self::map_to_response).collect()
+    }
+
+    /// All proposals with their effective votes
+    pub fn get_all_with_votes() -> Vec<(Proposal, Votes)> {
+        Self::get_all()
+            .into_iter()
+            .map(|(id, proposal)| {
+                let votes = VoteStorage::get_opt(id)
+                    .map(|(_, votes)| votes)
+                    .unwrap_or_else(|| Votes(vec![]));
+                (proposal, votes)
+            })
+            .collect()
+    }
+
     fn update_status(id: u64, status: Status) -> CanisterResult<ProposalEntry> {
         let (_, mut proposal) = Self::get(id)?;
         proposal.update_status(status);
@@ -73,10 +123,22 @@ impl ProposalStorage {
         let votes: Votes = VoteStorage::get(id)
             .map(|(_, votes)| votes)
             .unwrap_or_else(|_| Votes(vec![]));
+        let recipient_names = proposal
+            .content
+            .transfers()
+            .map(|(_, transfers)| transfers)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(account, _)| {
+                AddressBookStorage::find_name(&account).map(|name| RecipientName { account, name })
+            })
+            .collect();
+
         ProposalResponse {
             id,
             proposal,
             votes,
+            recipient_names,
         }
     }
 }
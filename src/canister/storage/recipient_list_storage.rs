@@ -0,0 +1,40 @@
+use ic_stable_structures::memory_manager::MemoryId;
+use icrc_ledger_types::icrc1::account::Account;
+
+use types::{RecipientList, RecipientListEntry};
+
+use super::{
+    StaticStorageRef, Storage, StorageInsertable, StorageQueryable, StorageUpdateable,
+    RECIPIENT_LISTS, RECIPIENT_LISTS_MEMORY_ID,
+};
+
+pub struct RecipientListStorage;
+
+impl Storage<u64, RecipientListEntry> for RecipientListStorage {
+    const NAME: &'static str = "recipient_lists";
+
+    fn storage() -> StaticStorageRef<u64, RecipientListEntry> {
+        &RECIPIENT_LISTS
+    }
+
+    fn memory_id() -> MemoryId {
+        RECIPIENT_LISTS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, RecipientListEntry> for RecipientListStorage {}
+impl StorageInsertable<RecipientListEntry> for RecipientListStorage {}
+impl StorageUpdateable<u64, RecipientListEntry> for RecipientListStorage {}
+
+impl RecipientListStorage {
+    pub fn find_entry(
+        account: &Account,
+        list: &RecipientList,
+    ) -> Option<(u64, RecipientListEntry)> {
+        Self::find(|_, entry| &entry.account == account && &entry.list == list)
+    }
+
+    pub fn is_listed(account: &Account, list: &RecipientList) -> bool {
+        Self::find_entry(account, list).is_some()
+    }
+}
@@ -0,0 +1,25 @@
+use candid::Principal;
+use ic_stable_structures::memory_manager::MemoryId;
+
+use types::Roles;
+
+use super::{
+    StaticStorageRef, Storage, StorageQueryable, StorageUpdateable, ROLES, ROLES_MEMORY_ID,
+};
+
+pub struct RoleStorage;
+
+impl Storage<Principal, Roles> for RoleStorage {
+    const NAME: &'static str = "roles";
+
+    fn storage() -> StaticStorageRef<Principal, Roles> {
+        &ROLES
+    }
+
+    fn memory_id() -> MemoryId {
+        ROLES_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<Principal, Roles> for RoleStorage {}
+impl StorageUpdateable<Principal, Roles> for RoleStorage {}
@@ -0,0 +1,25 @@
+use candid::Principal;
+use ic_stable_structures::memory_manager::MemoryId;
+
+use types::Rotation;
+
+use super::{
+    StaticStorageRef, Storage, StorageQueryable, StorageUpdateable, ROTATIONS, ROTATIONS_MEMORY_ID,
+};
+
+pub struct RotationStorage;
+
+impl Storage<Principal, Rotation> for RotationStorage {
+    const NAME: &'static str = "rotations";
+
+    fn storage() -> StaticStorageRef<Principal, Rotation> {
+        &ROTATIONS
+    }
+
+    fn memory_id() -> MemoryId {
+        ROTATIONS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<Principal, Rotation> for RotationStorage {}
+impl StorageUpdateable<Principal, Rotation> for RotationStorage {}
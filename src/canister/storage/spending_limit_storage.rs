@@ -0,0 +1,26 @@
+use ic_stable_structures::memory_manager::MemoryId;
+
+use types::SpendingLimit;
+
+use super::{
+    StaticStorageRef, Storage, StorageInsertable, StorageQueryable, StorageUpdateable,
+    SPENDING_LIMITS, SPENDING_LIMITS_MEMORY_ID,
+};
+
+pub struct SpendingLimitStorage;
+
+impl Storage<u64, SpendingLimit> for SpendingLimitStorage {
+    const NAME: &'static str = "spending_limits";
+
+    fn storage() -> StaticStorageRef<u64, SpendingLimit> {
+        &SPENDING_LIMITS
+    }
+
+    fn memory_id() -> MemoryId {
+        SPENDING_LIMITS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, SpendingLimit> for SpendingLimitStorage {}
+impl StorageInsertable<SpendingLimit> for SpendingLimitStorage {}
+impl StorageUpdateable<u64, SpendingLimit> for SpendingLimitStorage {}
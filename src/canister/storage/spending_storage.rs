@@ -0,0 +1,36 @@
+use candid::Nat;
+use ic_stable_structures::memory_manager::MemoryId;
+
+use types::{Spending, SpendingLimit};
+
+use super::{
+    StaticStorageRef, Storage, StorageInsertable, StorageQueryable, StorageUpdateable, SPENDINGS,
+    SPENDINGS_MEMORY_ID,
+};
+
+pub struct SpendingStorage;
+
+impl Storage<u64, Spending> for SpendingStorage {
+    const NAME: &'static str = "spendings";
+
+    fn storage() -> StaticStorageRef<u64, Spending> {
+        &SPENDINGS
+    }
+
+    fn memory_id() -> MemoryId {
+        SPENDINGS_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, Spending> for SpendingStorage {}
+impl StorageInsertable<Spending> for SpendingStorage {}
+impl StorageUpdateable<u64, Spending> for SpendingStorage {}
+
+impl SpendingStorage {
+    /// Sum of the amounts spent since `since` that count towards the given limit, in the
+    /// denomination of the limit
+    pub fn consumed(limit: &SpendingLimit, since: u64) -> Nat {
+        let spendings = Self::filter(|_, spending| spending.canister_id == limit.canister_id);
+        limit.consumed(spendings.iter().map(|(_, spending)| spending), since)
+    }
+}
@@ -6,7 +6,11 @@ use ic_stable_structures::{
     Cell, DefaultMemoryImpl, StableBTreeMap,
 };
 
-use types::{models::AirdropTransfers, Metadata, Proposal, Votes};
+use types::{
+    models::AirdropTransfers, AddressBookEntry, BallotKey, Config, Delegation, GroupMember,
+    Invitation, Metadata, Ownership, PauseStatus, Proposal, RecipientListEntry, Roles, Rotation,
+    Spending, SpendingLimit, VoteHistory, Votes,
+};
 
 pub type Memory = VirtualMemory<DefaultMemoryImpl>;
 
@@ -15,7 +19,6 @@ pub type Memory = VirtualMemory<DefaultMemoryImpl>;
 /// These IDs are used to identify the different stores in the `MemoryManager`.
 /// # Warning
 /// These IDs should not be changed. New IDs should be added to the end of the list
-
 pub static OWNER_MEMORY_ID: MemoryId = MemoryId::new(0);
 pub static METADATA_MEMORY_ID: MemoryId = MemoryId::new(1);
 
@@ -27,6 +30,36 @@ pub static PROPOSALS_MEMORY_ID: MemoryId = MemoryId::new(4);
 
 pub static VOTES_MEMORY_ID: MemoryId = MemoryId::new(5);
 
+pub static SPENDING_LIMITS_MEMORY_ID: MemoryId = MemoryId::new(6);
+
+pub static SPENDINGS_MEMORY_ID: MemoryId = MemoryId::new(7);
+
+pub static CONFIG_MEMORY_ID: MemoryId = MemoryId::new(8);
+
+pub static ADDRESS_BOOK_MEMORY_ID: MemoryId = MemoryId::new(9);
+
+pub static RECIPIENT_LISTS_MEMORY_ID: MemoryId = MemoryId::new(10);
+
+pub static VOTE_HISTORY_MEMORY_ID: MemoryId = MemoryId::new(11);
+
+pub static DELEGATIONS_MEMORY_ID: MemoryId = MemoryId::new(12);
+
+pub static BALLOT_KEYS_MEMORY_ID: MemoryId = MemoryId::new(13);
+
+pub static ROTATIONS_MEMORY_ID: MemoryId = MemoryId::new(14);
+
+pub static INVITATIONS_MEMORY_ID: MemoryId = MemoryId::new(15);
+
+pub static OWNERSHIP_MEMORY_ID: MemoryId = MemoryId::new(16);
+
+pub static RECOVERY_SUPPORT_MEMORY_ID: MemoryId = MemoryId::new(17);
+
+pub static ROLES_MEMORY_ID: MemoryId = MemoryId::new(18);
+
+pub static GROUP_MEMBERS_MEMORY_ID: MemoryId = MemoryId::new(19);
+
+pub static PAUSE_MEMORY_ID: MemoryId = MemoryId::new(20);
+
 /// A reference to a `StableBTreeMap` that is wrapped in a `RefCell`.
 ///# Generics
 /// * `K` - The key type of the `StableBTreeMap`.
@@ -65,4 +98,67 @@ thread_local! {
     pub static VOTES: StorageRef<u64, Votes> = RefCell::new(
         StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(VOTES_MEMORY_ID)))
     );
+
+    pub static SPENDING_LIMITS: StorageRef<u64, SpendingLimit> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(SPENDING_LIMITS_MEMORY_ID)))
+    );
+
+    pub static SPENDINGS: StorageRef<u64, Spending> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(SPENDINGS_MEMORY_ID)))
+    );
+
+    pub static CONFIG: RefCell<Cell<Option<Config>, Memory>> = RefCell::new(
+        Cell::init(MEMORY_MANAGER.with(|p| p.borrow().get(CONFIG_MEMORY_ID)), None)
+            .expect("Failed to initialize config")
+    );
+
+    pub static ADDRESS_BOOK: StorageRef<String, AddressBookEntry> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(ADDRESS_BOOK_MEMORY_ID)))
+    );
+
+    pub static RECIPIENT_LISTS: StorageRef<u64, RecipientListEntry> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(RECIPIENT_LISTS_MEMORY_ID)))
+    );
+
+    pub static VOTE_HISTORY: StorageRef<u64, VoteHistory> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(VOTE_HISTORY_MEMORY_ID)))
+    );
+
+    pub static DELEGATIONS: StorageRef<u64, Delegation> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(DELEGATIONS_MEMORY_ID)))
+    );
+
+    pub static BALLOT_KEYS: StorageRef<Principal, BallotKey> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(BALLOT_KEYS_MEMORY_ID)))
+    );
+
+    pub static ROTATIONS: StorageRef<Principal, Rotation> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(ROTATIONS_MEMORY_ID)))
+    );
+
+    pub static INVITATIONS: StorageRef<Principal, Invitation> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(INVITATIONS_MEMORY_ID)))
+    );
+
+    pub static OWNERSHIP: RefCell<Cell<Option<Ownership>, Memory>> = RefCell::new(
+        Cell::init(MEMORY_MANAGER.with(|p| p.borrow().get(OWNERSHIP_MEMORY_ID)), None)
+            .expect("Failed to initialize ownership")
+    );
+
+    pub static RECOVERY_SUPPORT: StorageRef<Principal, Principal> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(RECOVERY_SUPPORT_MEMORY_ID)))
+    );
+
+    pub static ROLES: StorageRef<Principal, Roles> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(ROLES_MEMORY_ID)))
+    );
+
+    pub static GROUP_MEMBERS: StorageRef<Principal, GroupMember> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|p| p.borrow().get(GROUP_MEMBERS_MEMORY_ID)))
+    );
+
+    pub static PAUSE: RefCell<Cell<Option<PauseStatus>, Memory>> = RefCell::new(
+        Cell::init(MEMORY_MANAGER.with(|p| p.borrow().get(PAUSE_MEMORY_ID)), None)
+            .expect("Failed to initialize pause")
+    );
 }
@@ -0,0 +1,38 @@
+use ic_stable_structures::memory_manager::MemoryId;
+
+use types::{Vote, VoteHistory};
+
+use crate::result::CanisterResult;
+
+use super::{
+    StaticStorageRef, Storage, StorageQueryable, StorageUpdateable, VOTE_HISTORY,
+    VOTE_HISTORY_MEMORY_ID,
+};
+
+pub struct VoteHistoryStorage;
+
+impl Storage<u64, VoteHistory> for VoteHistoryStorage {
+    const NAME: &'static str = "vote_history";
+
+    fn storage() -> StaticStorageRef<u64, VoteHistory> {
+        &VOTE_HISTORY
+    }
+
+    fn memory_id() -> MemoryId {
+        VOTE_HISTORY_MEMORY_ID
+    }
+}
+
+impl StorageQueryable<u64, VoteHistory> for VoteHistoryStorage {}
+impl StorageUpdateable<u64, VoteHistory> for VoteHistoryStorage {}
+
+impl VoteHistoryStorage {
+    pub fn append(id: u64, vote: Vote) -> CanisterResult<(u64, VoteHistory)> {
+        let mut history = Self::get_opt(id)
+            .map(|(_, history)| history)
+            .unwrap_or_else(|| VoteHistory(vec![]));
+
+        history.0.push(vote);
+        Self::upsert(id, history)
+    }
+}
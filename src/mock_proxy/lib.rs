@@ -0,0 +1,85 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use candid::{CandidType, Principal};
+use ic_cdk::{query, update};
+use serde::Deserialize;
+
+#[derive(CandidType, Deserialize, Clone)]
+pub struct JoinedMemberResponse {
+    pub group_id: u64,
+    pub principal: Principal,
+    pub roles: Vec<String>,
+}
+
+thread_local! {
+    static GROUP_MEMBERS: RefCell<HashMap<u64, Vec<JoinedMemberResponse>>> =
+        RefCell::new(HashMap::new());
+}
+
+#[query]
+pub fn get_group_members(group_id: u64) -> Result<Vec<JoinedMemberResponse>, String> {
+    GROUP_MEMBERS.with(|members| {
+        members
+            .borrow()
+            .get(&group_id)
+            .cloned()
+            .ok_or_else(|| "Group not found".to_owned())
+    })
+}
+
+/// Sets the members of a group, with their roles
+#[update]
+pub fn set_group_members(group_id: u64, members: Vec<(Principal, Vec<String>)>) {
+    let members = members
+        .into_iter()
+        .map(|(principal, roles)| JoinedMemberResponse {
+            group_id,
+            principal,
+            roles,
+        })
+        .collect();
+
+    GROUP_MEMBERS.with(|m| m.borrow_mut().insert(group_id, members));
+}
+
+#[query(name = "__get_candid_interface_tmp_hack")]
+pub fn __export_did_tmp_() -> String {
+    candid::export_service!();
+    __export_service()
+}
+
+// The multisig canister reads the members with its own types, ignoring the error details
+#[test]
+pub fn test_get_group_members() {
+    use candid::{decode_one, encode_one, Reserved};
+
+    #[derive(CandidType, Deserialize)]
+    struct Member {
+        principal: Principal,
+        roles: Vec<String>,
+    }
+
+    let principal = Principal::from_slice(&[1]);
+    set_group_members(1, vec![(principal, vec!["admin".to_owned()])]);
+
+    let bytes = encode_one(get_group_members(1)).expect("Encode failed.");
+    let members: Result<Vec<Member>, Reserved> = decode_one(&bytes).expect("Decode failed.");
+    let members = members.expect("Group not found.");
+    assert!(members.len() == 1 && members[0].principal == principal);
+    assert_eq!(members[0].roles, vec!["admin".to_owned()]);
+
+    let bytes = encode_one(get_group_members(2)).expect("Encode failed.");
+    let members: Result<Vec<Member>, Reserved> = decode_one(&bytes).expect("Decode failed.");
+    assert!(members.is_err());
+}
+
+// Method used to save the candid interface to a file
+#[test]
+pub fn candid() {
+    use std::env;
+    use std::fs::write;
+    use std::path::PathBuf;
+
+    let dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    write(dir.join("mock_proxy.did"), __export_did_tmp_()).expect("Write failed.");
+}
@@ -0,0 +1,83 @@
+use candid::{CandidType, Nat, Principal};
+use ic_ledger_types::{AccountIdentifier, Subaccount};
+use icrc_ledger_types::icrc1::{account::Account, transfer::Memo};
+use serde::Deserialize;
+
+use crate::impl_storable_for;
+
+impl_storable_for!(AddressBookEntry);
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum Address {
+    Account(Account),
+    /// Legacy ICP ledger account identifier, only used to label recipients
+    AccountIdentifier(AccountIdentifier),
+}
+
+impl Address {
+    pub fn matches(&self, account: &Account) -> bool {
+        match self {
+            Address::Account(value) => value == account,
+            Address::AccountIdentifier(value) => {
+                let subaccount = Subaccount(account.subaccount.unwrap_or([0; 32]));
+                value == &AccountIdentifier::new(&account.owner, &subaccount)
+            }
+        }
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct AddressBookEntry {
+    pub address: Address,
+    pub updated_at: u64,
+}
+
+impl AddressBookEntry {
+    pub fn new(address: Address) -> Self {
+        Self {
+            address,
+            updated_at: ic_cdk::api::time(),
+        }
+    }
+}
+
+pub type AddressBookEntryEntry = (String, AddressBookEntry);
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct AddressBookProposalContent {
+    pub name: String,
+    pub address: Address,
+}
+
+/// A transfer to a recipient referenced by its address book name
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ContactTransfer {
+    pub name: String,
+    pub amount: Nat,
+    pub memo: Option<Memo>,
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ContactTransferProposalContent {
+    pub canister_id: Principal,
+    pub transfer: ContactTransfer,
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ContactAirdropProposalContent {
+    pub canister_id: Principal,
+    pub transfers: Vec<ContactTransfer>,
+}
+
+/// Transfers to address book names, resolved to `Transfer` and `Airdrop` content when proposed
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ContactContent {
+    Transfer(ContactTransferProposalContent),
+    Airdrop(ContactAirdropProposalContent),
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RecipientName {
+    pub account: Account,
+    pub name: String,
+}
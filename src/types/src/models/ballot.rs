@@ -0,0 +1,78 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::{impl_storable_for, VoteKind};
+
+impl_storable_for!(BallotKey);
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum SignatureScheme {
+    /// 32 bytes public key, 64 bytes signature
+    Ed25519,
+    /// SEC1 encoded public key, 64 bytes ECDSA signature over the SHA-256 hash of the message
+    Secp256k1,
+}
+
+/// Key registered by a member to sign ballots offline
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct BallotKey {
+    pub scheme: SignatureScheme,
+    pub public_key: Vec<u8>,
+    /// Nonce of the last submitted ballot, new ballots need a higher nonce
+    pub last_nonce: Option<u64>,
+    pub registered_at: u64,
+}
+
+impl BallotKey {
+    pub fn new(scheme: SignatureScheme, public_key: Vec<u8>) -> Self {
+        Self {
+            scheme,
+            public_key,
+            last_nonce: None,
+            registered_at: ic_cdk::api::time(),
+        }
+    }
+}
+
+pub type BallotKeyEntry = (Principal, BallotKey);
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Ballot {
+    pub proposal_id: u64,
+    pub vote: VoteKind,
+    pub nonce: u64,
+    pub expires_at: u64,
+}
+
+impl Ballot {
+    const DOMAIN: &'static [u8] = b"multisig-ballot";
+
+    /// The message to sign, bound to the multisig canister to prevent replays on other wallets
+    /// # Format
+    /// `"multisig-ballot" | canister id length (1 byte) | canister id | proposal id (u64 BE) |
+    /// vote (1 byte, 0 = approve, 1 = reject, 2 = abstain) | nonce (u64 BE) | expires at (u64 BE)`
+    pub fn message(&self, canister_id: &Principal) -> Vec<u8> {
+        let canister_id = canister_id.as_slice();
+        let vote: u8 = match self.vote {
+            VoteKind::Approve => 0,
+            VoteKind::Reject => 1,
+            VoteKind::Abstain => 2,
+        };
+
+        let mut message = Self::DOMAIN.to_vec();
+        message.push(canister_id.len() as u8);
+        message.extend_from_slice(canister_id);
+        message.extend_from_slice(&self.proposal_id.to_be_bytes());
+        message.push(vote);
+        message.extend_from_slice(&self.nonce.to_be_bytes());
+        message.extend_from_slice(&self.expires_at.to_be_bytes());
+        message
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct SignedBallot {
+    pub voter: Principal,
+    pub ballot: Ballot,
+    pub signature: Vec<u8>,
+}
@@ -0,0 +1,276 @@
+use std::time::Duration;
+
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::{impl_storable_for, Content};
+
+impl_storable_for!(Config);
+
+/// The exchange rate canister (XRC) on the Internet Computer mainnet
+pub const EXCHANGE_RATE_CANISTER: &str = "uf6dk-hyaaa-aaaaq-qaaaq-cai";
+
+pub const HOUR_IN_NANOS: u64 = Duration::from_secs(60 * 60).as_nanos() as u64;
+pub const DAY_IN_NANOS: u64 = 24 * HOUR_IN_NANOS;
+
+/// The config is stored and part of `UpdateConfig` proposals, so fields added after the first
+/// release are optional to keep decoding stored configs and proposals. The defaults of unset fields
+/// are applied by the getters
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Canister used to convert ledger amounts to USD, can be replaced by a mock for local testing
+    pub exchange_rate_canister: Principal,
+    /// Only allow transfers and airdrops to recipients on the allowlist
+    pub enforce_recipient_allowlist: Option<bool>,
+    pub voting_periods: Option<VotingPeriods>,
+    /// Without rules a proposal needs the approval of a majority of all members
+    pub tally_rules: Option<TallyRules>,
+    /// Votes can't be changed once cast
+    pub lock_votes: Option<bool>,
+    /// Members can only be changed through approved proposals, disables the owner endpoints
+    pub membership_by_proposal_only: Option<bool>,
+    pub member_bounds: Option<MemberBounds>,
+    pub owner_recovery: Option<OwnerRecoveryRules>,
+    /// Defaults to the owner and the principals with the proposer role
+    pub proposal_creators: Option<ProposalCreators>,
+    /// Who can read the proposals, votes, members and other data of the wallet, public by default
+    pub visibility: Option<Visibility>,
+    /// Roles of the group that give access when restricted to the group, any group member has
+    /// access when empty
+    pub group_roles: Option<Vec<String>>,
+    /// Principal, like a security council canister, that can pause the wallet without being a member
+    pub guardian: Option<Principal>,
+    /// Asset symbols used by the exchange rate canister for ledgers of which the token symbol
+    /// isn't listed, like BTC for ckBTC
+    pub xrc_symbols: Option<Vec<(Principal, String)>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            exchange_rate_canister: Principal::from_text(EXCHANGE_RATE_CANISTER)
+                .expect("Invalid exchange rate canister id"),
+            enforce_recipient_allowlist: None,
+            voting_periods: None,
+            tally_rules: None,
+            lock_votes: None,
+            membership_by_proposal_only: None,
+            member_bounds: None,
+            owner_recovery: None,
+            proposal_creators: None,
+            visibility: None,
+            group_roles: None,
+            guardian: None,
+            xrc_symbols: None,
+        }
+    }
+}
+
+impl Config {
+    pub fn enforce_recipient_allowlist(&self) -> bool {
+        self.enforce_recipient_allowlist.unwrap_or_default()
+    }
+
+    pub fn voting_periods(&self) -> VotingPeriods {
+        self.voting_periods.clone().unwrap_or_default()
+    }
+
+    pub fn lock_votes(&self) -> bool {
+        self.lock_votes.unwrap_or_default()
+    }
+
+    pub fn membership_by_proposal_only(&self) -> bool {
+        self.membership_by_proposal_only.unwrap_or_default()
+    }
+
+    pub fn member_bounds(&self) -> MemberBounds {
+        self.member_bounds.clone().unwrap_or_default()
+    }
+
+    pub fn owner_recovery(&self) -> OwnerRecoveryRules {
+        self.owner_recovery.clone().unwrap_or_default()
+    }
+
+    pub fn proposal_creators(&self) -> ProposalCreators {
+        self.proposal_creators
+            .clone()
+            .unwrap_or(ProposalCreators::Members)
+    }
+
+    pub fn visibility(&self) -> Visibility {
+        self.visibility.clone().unwrap_or(Visibility::Public)
+    }
+
+    pub fn group_roles(&self) -> Vec<String> {
+        self.group_roles.clone().unwrap_or_default()
+    }
+
+    pub fn xrc_symbol(&self, canister_id: Principal) -> Option<String> {
+        self.xrc_symbols
+            .as_ref()?
+            .iter()
+            .find(|(ledger, _)| ledger == &canister_id)
+            .map(|(_, symbol)| symbol.clone())
+    }
+
+    /// Whether this config only removes or replaces the guardian of the current config
+    pub fn only_changes_guardian(&self, current: &Config) -> bool {
+        self.guardian != current.guardian
+            && self
+                == &Config {
+                    guardian: self.guardian,
+                    ..current.clone()
+                }
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum Visibility {
+    /// Any authenticated principal
+    Public,
+    /// The members of the group with one of the group roles and the principals that can view
+    /// the wallet
+    Group,
+    /// The owner, the whitelisted members and the principals with a role
+    Members,
+}
+
+/// Who can create proposals
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ProposalCreators {
+    Owner,
+    /// The owner and the whitelisted members
+    Members,
+    /// The owner and the principals with the proposer role
+    Roles,
+    /// The owner and the members of the group with one of the group roles
+    Group,
+}
+
+/// When the members can reassign the ownership without the owner
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct OwnerRecoveryRules {
+    /// Time in nanoseconds the owner has to be inactive
+    pub inactivity_period: u64,
+    /// Percentage of the members other than the owner that has to support the new owner
+    pub threshold: u8,
+}
+
+impl Default for OwnerRecoveryRules {
+    fn default() -> Self {
+        Self {
+            inactivity_period: 90 * DAY_IN_NANOS,
+            threshold: 67,
+        }
+    }
+}
+
+/// Minimum and maximum amount of members, including the owner
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct MemberBounds {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl Default for MemberBounds {
+    fn default() -> Self {
+        Self { min: 3, max: 3 }
+    }
+}
+
+/// Rules to decide the outcome of a proposal, in percentages
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct TallyRules {
+    /// Minimum participation of the members, abstentions included
+    pub quorum: u8,
+    /// Minimum share of approvals among the approving and rejecting votes
+    pub approval: u8,
+}
+
+/// Bounds and defaults of the voting period of proposals, in nanoseconds
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct VotingPeriods {
+    pub min: u64,
+    pub max: u64,
+    pub transfer: u64,
+    pub airdrop: u64,
+    /// Default for proposals that change the settings of the multisig
+    pub settings: u64,
+}
+
+impl Default for VotingPeriods {
+    fn default() -> Self {
+        Self {
+            min: HOUR_IN_NANOS,
+            max: 30 * DAY_IN_NANOS,
+            transfer: DAY_IN_NANOS,
+            airdrop: DAY_IN_NANOS,
+            settings: DAY_IN_NANOS,
+        }
+    }
+}
+
+impl VotingPeriods {
+    pub fn default_for(&self, content: &Content) -> u64 {
+        match content {
+            Content::Transfer(_) => self.transfer,
+            Content::Airdrop(_) => self.airdrop,
+            _ => self.settings,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use candid::{CandidType, Decode, Encode, Principal};
+
+    use crate::{Config, ProposalCreators};
+
+    #[test]
+    fn test_decode_first_release() {
+        #[derive(CandidType)]
+        struct FirstConfig {
+            exchange_rate_canister: Principal,
+        }
+
+        let bytes = Encode!(&FirstConfig {
+            exchange_rate_canister: Principal::anonymous(),
+        })
+        .unwrap();
+
+        let config = Decode!(&bytes, Config).unwrap();
+        assert_eq!(config.exchange_rate_canister, Principal::anonymous());
+        assert_eq!(config.member_bounds().min, 3);
+        assert!(config.proposal_creators() == ProposalCreators::Members);
+    }
+
+    #[test]
+    fn test_only_changes_guardian() {
+        let guardian = |i: u8| Some(Principal::from_slice(&[i]));
+        let current = Config {
+            guardian: guardian(1),
+            ..Config::default()
+        };
+
+        let removed = Config {
+            guardian: None,
+            ..current.clone()
+        };
+        assert!(removed.only_changes_guardian(&current));
+
+        let replaced = Config {
+            guardian: guardian(2),
+            ..current.clone()
+        };
+        assert!(replaced.only_changes_guardian(&current));
+
+        assert!(!current.only_changes_guardian(&current));
+
+        let other = Config {
+            guardian: None,
+            lock_votes: Some(true),
+            ..current.clone()
+        };
+        assert!(!other.only_changes_guardian(&current));
+    }
+}
@@ -0,0 +1,79 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::impl_storable_for;
+
+impl_storable_for!(Delegation);
+
+/// A member letting another member vote on their behalf until `expires_at`
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Delegation {
+    pub delegator: Principal,
+    pub delegate: Principal,
+    pub expires_at: u64,
+    pub created_at: u64,
+    pub revoked_at: Option<u64>,
+}
+
+impl Delegation {
+    pub fn new(delegator: Principal, delegate: Principal, expires_at: u64) -> Self {
+        Self {
+            delegator,
+            delegate,
+            expires_at,
+            created_at: ic_cdk::api::time(),
+            revoked_at: None,
+        }
+    }
+
+    pub fn is_active(&self, now: u64) -> bool {
+        self.revoked_at.is_none() && now < self.expires_at
+    }
+
+    /// Moves the delegation of a member to the new principal of the member
+    pub fn rotate(&mut self, old: &Principal, new: Principal) {
+        if &self.delegator == old {
+            self.delegator = new;
+        }
+        if &self.delegate == old {
+            self.delegate = new;
+        }
+    }
+
+    pub fn revoke(&mut self) {
+        self.revoked_at = Some(ic_cdk::api::time());
+    }
+}
+
+pub type DelegationEntry = (u64, Delegation);
+
+#[cfg(test)]
+mod test {
+    use candid::Principal;
+
+    use super::Delegation;
+
+    #[test]
+    fn test_rotate() {
+        let principal = |i: u8| Principal::from_slice(&[i]);
+        let delegation = Delegation {
+            delegator: principal(0),
+            delegate: principal(1),
+            expires_at: 0,
+            created_at: 0,
+            revoked_at: None,
+        };
+
+        let mut rotated = delegation.clone();
+        rotated.rotate(&principal(0), principal(9));
+        assert!(rotated.delegator == principal(9) && rotated.delegate == principal(1));
+
+        let mut rotated = delegation.clone();
+        rotated.rotate(&principal(1), principal(9));
+        assert!(rotated.delegator == principal(0) && rotated.delegate == principal(9));
+
+        let mut rotated = delegation.clone();
+        rotated.rotate(&principal(2), principal(9));
+        assert!(rotated == delegation);
+    }
+}
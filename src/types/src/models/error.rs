@@ -36,6 +36,14 @@ impl Error {
         Self::new(ErrorKind::ValidationError(Box::new(validation_response)))
     }
 
+    pub fn spending_limit_exceeded() -> Self {
+        Self::new(ErrorKind::SpendingLimitExceeded)
+    }
+
+    pub fn recipient_not_allowed() -> Self {
+        Self::new(ErrorKind::RecipientNotAllowed)
+    }
+
     pub fn serialize() -> Self {
         Self::new(ErrorKind::SerializeError)
     }
@@ -109,6 +117,8 @@ pub enum ErrorKind {
     Duplicate,
     ValidationError(Box<Vec<ValidationResponse>>),
     InsufficientBalance,
+    SpendingLimitExceeded,
+    RecipientNotAllowed,
     SerializeError,
     DeserializeError,
 }
@@ -136,6 +146,8 @@ impl fmt::Display for ErrorKind {
             Duplicate => write!(f, "Duplicate"),
             ValidationError(_) => write!(f, "ValidationError"),
             InsufficientBalance => write!(f, "InsufficientBalance"),
+            SpendingLimitExceeded => write!(f, "SpendingLimitExceeded"),
+            RecipientNotAllowed => write!(f, "RecipientNotAllowed"),
             SerializeError => write!(f, "SerializeError"),
             DeserializeError => write!(f, "DeserializeError"),
         }
@@ -0,0 +1,43 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::impl_storable_for;
+
+impl_storable_for!(GroupMember);
+
+/// A member of the group of the wallet, cached from the proxy canister
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct GroupMember {
+    pub roles: Vec<String>,
+    pub refreshed_at: u64,
+}
+
+impl GroupMember {
+    /// Whether the member has one of the roles, any role when empty. Members cached longer than
+    /// `validity` ago have no access, as the group could have changed since
+    pub fn has_access(&self, roles: &[String], now: u64, validity: u64) -> bool {
+        let is_fresh = now.saturating_sub(self.refreshed_at) <= validity;
+        is_fresh && (roles.is_empty() || self.roles.iter().any(|role| roles.contains(role)))
+    }
+}
+
+pub type GroupMemberEntry = (Principal, GroupMember);
+
+#[cfg(test)]
+mod test {
+    use super::GroupMember;
+
+    #[test]
+    fn test_has_access() {
+        let member = GroupMember {
+            roles: vec!["admin".to_owned()],
+            refreshed_at: 100,
+        };
+
+        assert!(member.has_access(&[], 150, 100));
+        assert!(member.has_access(&["admin".to_owned()], 200, 100));
+        assert!(!member.has_access(&["owner".to_owned()], 150, 100));
+        // The cache is too old
+        assert!(!member.has_access(&[], 201, 100));
+    }
+}
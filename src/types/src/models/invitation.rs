@@ -0,0 +1,29 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::impl_storable_for;
+
+impl_storable_for!(Invitation);
+
+/// A pending invitation of a principal added to the whitelist, keyed by the invited principal.
+/// The principal only counts as a member after accepting the invitation
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Invitation {
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+impl Invitation {
+    pub fn new(expires_at: u64) -> Self {
+        Self {
+            created_at: ic_cdk::api::time(),
+            expires_at,
+        }
+    }
+
+    pub fn is_active(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
+}
+
+pub type InvitationEntry = (Principal, Invitation);
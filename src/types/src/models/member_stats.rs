@@ -0,0 +1,120 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::{Proposal, Votes};
+
+/// Voting activity of a member over all proposals
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct MemberStats {
+    pub member: Principal,
+    /// Direct votes cast on proposals created by other members
+    pub votes_cast: u64,
+    /// Proposals created by other members the member could vote on
+    pub eligible_proposals: u64,
+    /// Percentage of the eligible proposals the member voted on
+    pub participation_rate: u8,
+    /// Average time in nanoseconds between the creation of a proposal and the vote of the member
+    pub average_time_to_vote: Option<u64>,
+    pub proposals_created: u64,
+}
+
+impl MemberStats {
+    /// # Note
+    /// The implicit approval of the creator is not counted as a vote, proposals created before the
+    /// member joined are counted as eligible as the membership history is not kept
+    pub fn new(member: Principal, proposals: &[(Proposal, Votes)]) -> Self {
+        let mut votes_cast: u64 = 0;
+        let mut eligible_proposals: u64 = 0;
+        let mut proposals_created: u64 = 0;
+        let mut total_time_to_vote: u64 = 0;
+
+        for (proposal, votes) in proposals {
+            if proposal.creator == member {
+                proposals_created += 1;
+                continue;
+            }
+
+            eligible_proposals += 1;
+
+            if let Some(vote) = votes.0.iter().find(|v| v.voter == member) {
+                votes_cast += 1;
+                total_time_to_vote += vote.created_at.saturating_sub(proposal.created_at);
+            }
+        }
+
+        let participation_rate = match eligible_proposals {
+            0 => 0,
+            eligible => (votes_cast * 100 / eligible) as u8,
+        };
+
+        Self {
+            member,
+            votes_cast,
+            eligible_proposals,
+            participation_rate,
+            average_time_to_vote: total_time_to_vote.checked_div(votes_cast),
+            proposals_created,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use candid::Principal;
+
+    use crate::{Config, Content, MemberStats, Proposal, Status, Vote, VoteKind, Votes};
+
+    fn principal(id: u8) -> Principal {
+        Principal::from_slice(&[id])
+    }
+
+    fn proposal(creator: u8, created_at: u64, voters: &[(u8, u64)]) -> (Proposal, Votes) {
+        let proposal = Proposal {
+            status: Status::Pending,
+            creator: principal(creator),
+            sent_at: None,
+            voting_period: 100,
+            created_at,
+            content: Content::UpdateConfig(Config::default()),
+            threshold: None,
+            valuation: None,
+            details: None,
+            veto: None,
+        };
+
+        let votes = voters
+            .iter()
+            .map(|(voter, created_at)| Vote {
+                voter: principal(*voter),
+                kind: VoteKind::Approve,
+                created_at: *created_at,
+                rationale: None,
+                updated_at: None,
+            })
+            .collect();
+
+        (proposal, Votes(votes))
+    }
+
+    #[test]
+    fn test_member_stats() {
+        let proposals = vec![
+            proposal(1, 0, &[(1, 0), (2, 10)]),
+            proposal(1, 100, &[(1, 100), (2, 130)]),
+            proposal(2, 200, &[(2, 200)]),
+            proposal(1, 300, &[(1, 300)]),
+        ];
+
+        let stats = MemberStats::new(principal(2), &proposals);
+        assert_eq!(stats.votes_cast, 2);
+        assert_eq!(stats.eligible_proposals, 3);
+        assert_eq!(stats.participation_rate, 66);
+        assert_eq!(stats.average_time_to_vote, Some(20));
+        assert_eq!(stats.proposals_created, 1);
+
+        let stats = MemberStats::new(principal(3), &proposals);
+        assert_eq!(stats.votes_cast, 0);
+        assert_eq!(stats.participation_rate, 0);
+        assert_eq!(stats.average_time_to_vote, None);
+    }
+}
@@ -9,11 +9,16 @@ impl_storable_for!(Metadata);
 pub struct Metadata {
     pub group_id: u64,
     pub proxy_canister: Principal,
-    pub index_canister: Principal,
+    /// Not set for standalone deployments, notifications are only sent to an index canister
+    pub index_canister: Option<Principal>,
 }
 
 impl Metadata {
-    pub fn new(group_id: u64, proxy_canister: Principal, index_canister: Principal) -> Self {
+    pub fn new(
+        group_id: u64,
+        proxy_canister: Principal,
+        index_canister: Option<Principal>,
+    ) -> Self {
         Self {
             group_id,
             proxy_canister,
@@ -1,18 +1,52 @@
+mod address_book;
 mod airdrop_transfer;
 mod airdrop_transfers;
+mod ballot;
+mod config;
+mod delegation;
 mod error;
+mod group_member;
+mod invitation;
 mod macros;
+mod member_stats;
 mod metadata;
+mod ownership;
+mod pause;
 mod proposal;
+mod proposal_details;
+mod recipient_list;
+mod role;
+mod rotation;
+mod spending;
+mod spending_limit;
 mod validation;
+mod valuation;
 mod vote;
+mod vote_history;
 mod votes;
 
+pub use address_book::*;
 pub use airdrop_transfer::*;
 pub use airdrop_transfers::*;
+pub use ballot::*;
+pub use config::*;
+pub use delegation::*;
 pub use error::*;
+pub use group_member::*;
+pub use invitation::*;
+pub use member_stats::*;
 pub use metadata::*;
+pub use ownership::*;
+pub use pause::*;
 pub use proposal::*;
+pub use proposal_details::*;
+pub use recipient_list::*;
+pub use role::*;
+pub use rotation::*;
+pub use spending::*;
+pub use spending_limit::*;
 pub use validation::*;
+pub use valuation::*;
 pub use vote::*;
+pub use vote_history::*;
 pub use votes::*;
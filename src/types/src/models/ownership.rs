@@ -0,0 +1,38 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::impl_storable_for;
+
+impl_storable_for!(Ownership);
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct Ownership {
+    /// Last time the owner proposed or voted, used to decide if the ownership can be recovered
+    pub last_active_at: Option<u64>,
+    /// Principal the owner handed the ownership over to, until it accepts
+    pub pending_owner: Option<PendingOwner>,
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct PendingOwner {
+    pub principal: Principal,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+impl PendingOwner {
+    pub fn new(principal: Principal, expires_at: u64) -> Self {
+        Self {
+            principal,
+            created_at: ic_cdk::api::time(),
+            expires_at,
+        }
+    }
+
+    pub fn is_active(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
+}
+
+/// Members supporting the recovery of the ownership as `(supporter, candidate)` pairs
+pub type RecoverySupportEntry = (Principal, Principal);
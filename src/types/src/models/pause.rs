@@ -0,0 +1,15 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::impl_storable_for;
+
+impl_storable_for!(PauseStatus);
+
+/// While paused, no proposals can be created or executed except for unpausing
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct PauseStatus {
+    pub paused: bool,
+    pub changed_by: Option<Principal>,
+    pub changed_at: Option<u64>,
+    pub reason: Option<String>,
+}
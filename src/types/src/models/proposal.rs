@@ -1,6 +1,10 @@
-use crate::{impl_storable_for, Votes};
-use candid::{CandidType, Principal};
-use icrc_ledger_types::icrc1::transfer::TransferArg;
+use crate::{
+    impl_storable_for, AddressBookProposalContent, Config, ProposalDetails,
+    RecipientListProposalContent, RecipientName, Role, SpendingLimit, TallyRules, UsdValuation,
+    Votes,
+};
+use candid::{CandidType, Nat, Principal};
+use icrc_ledger_types::icrc1::{account::Account, transfer::TransferArg};
 use serde::Deserialize;
 
 impl_storable_for!(Proposal);
@@ -13,6 +17,17 @@ pub struct Proposal {
     pub voting_period: u64,
     pub created_at: u64,
     pub content: Content,
+    /// Percentage of the members required to approve, a simple majority is used when not set
+    pub threshold: Option<u8>,
+    pub valuation: Option<UsdValuation>,
+    pub details: Option<ProposalDetails>,
+    pub veto: Option<Veto>,
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Veto {
+    pub reason: String,
+    pub vetoed_at: u64,
 }
 
 #[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
@@ -27,14 +42,71 @@ pub struct TransferProposalContent {
     pub args: TransferArg,
 }
 
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReplaceMemberProposalContent {
+    pub old: Principal,
+    pub new: Principal,
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct AssignRolesProposalContent {
+    pub principal: Principal,
+    pub roles: Vec<Role>,
+}
+
 #[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
 pub enum Content {
     Airdrop(AirdropProposalContent),
     Transfer(TransferProposalContent),
+    SetSpendingLimit(SpendingLimit),
+    RemoveSpendingLimit(u64),
+    UpdateConfig(Config),
+    SetAddressBookEntry(AddressBookProposalContent),
+    RemoveAddressBookEntry(String),
+    AddRecipient(RecipientListProposalContent),
+    RemoveRecipient(RecipientListProposalContent),
+    AddMember(Principal),
+    RemoveMember(Principal),
+    ReplaceMember(ReplaceMemberProposalContent),
+    UpdateTallyRules(TallyRules),
+    /// Transfers the ownership to a whitelisted member, the current owner becomes a member
+    ChangeOwner(Principal),
+    /// Lifts the emergency pause, the only proposal that can be created and executed while paused
+    Unpause,
+    /// Replaces the assigned roles of a principal, the admin role included
+    AssignRoles(AssignRolesProposalContent),
+}
+
+impl Content {
+    /// The ledger canister and the receivers with amounts of the transfers in the content
+    pub fn transfers(&self) -> Option<(Principal, Vec<(Account, Nat)>)> {
+        match self {
+            Content::Transfer(content) => Some((
+                content.canister_id,
+                vec![(content.args.to, content.args.amount.clone())],
+            )),
+            Content::Airdrop(content) => Some((
+                content.canister_id,
+                content
+                    .args
+                    .iter()
+                    .map(|args| (args.to, args.amount.clone()))
+                    .collect(),
+            )),
+            _ => None,
+        }
+    }
 }
 
 impl Proposal {
-    pub fn new(creator: Principal, content: Content, voting_period: u64) -> Self {
+    pub fn new(
+        creator: Principal,
+        content: Content,
+        voting_period: u64,
+        threshold: Option<u8>,
+        valuation: Option<UsdValuation>,
+        details: Option<ProposalDetails>,
+    ) -> Self {
         Self {
             status: Status::Pending,
             creator,
@@ -42,8 +114,18 @@ impl Proposal {
             voting_period,
             created_at: ic_cdk::api::time(),
             content,
+            threshold,
+            valuation,
+            details,
+            veto: None,
         }
     }
+
+    /// The time at which the voting period ends
+    pub fn deadline(&self) -> u64 {
+        self.created_at.saturating_add(self.voting_period)
+    }
+
     pub fn status(&self) -> Status {
         self.status.clone()
     }
@@ -55,6 +137,11 @@ impl Proposal {
     pub fn set_sent_at(&mut self, sent_at: u64) {
         self.sent_at = Some(sent_at);
     }
+
+    pub fn veto(&mut self, reason: String, vetoed_at: u64) {
+        self.status = Status::Vetoed;
+        self.veto = Some(Veto { reason, vetoed_at });
+    }
 }
 
 pub type ProposalEntry = (u64, Proposal);
@@ -66,6 +153,7 @@ pub enum Status {
     Rejected,
     Expired,
     Deadlock,
+    Vetoed,
 }
 
 #[derive(CandidType, Deserialize, PartialEq, Eq, Clone)]
@@ -73,4 +161,14 @@ pub struct ProposalResponse {
     pub id: u64,
     pub proposal: Proposal,
     pub votes: Votes,
+    /// Address book names of the known recipients of the proposal
+    pub recipient_names: Vec<RecipientName>,
+}
+
+/// A single proposal of a batch created with `propose_many`
+#[derive(CandidType, Deserialize, Clone)]
+pub struct ProposalInput {
+    pub content: Content,
+    pub voting_period: Option<u64>,
+    pub details: Option<ProposalDetails>,
 }
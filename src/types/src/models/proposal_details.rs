@@ -0,0 +1,53 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+/// Human readable information about a proposal
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ProposalDetails {
+    pub title: String,
+    /// Markdown formatted description
+    pub description: Option<String>,
+    pub links: Vec<String>,
+}
+
+impl ProposalDetails {
+    /// Whether every term is found in the title, description or links, ignoring case
+    pub fn matches(&self, terms: &[String]) -> bool {
+        let text = [
+            self.title.as_str(),
+            self.description.as_deref().unwrap_or_default(),
+            &self.links.join(" "),
+        ]
+        .join(" ")
+        .to_lowercase();
+
+        terms.iter().all(|term| text.contains(&term.to_lowercase()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ProposalDetails;
+
+    #[test]
+    fn test_matches() {
+        let details = ProposalDetails {
+            title: "Payroll March".to_owned(),
+            description: Some("Monthly **salaries** for the team".to_owned()),
+            links: vec!["https://example.com/invoices/42".to_owned()],
+        };
+
+        let terms = |query: &str| {
+            query
+                .split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+
+        assert!(details.matches(&terms("payroll")));
+        assert!(details.matches(&terms("MARCH salaries")));
+        assert!(details.matches(&terms("invoices/42")));
+        assert!(details.matches(&terms("")));
+        assert!(!details.matches(&terms("payroll april")));
+    }
+}
@@ -0,0 +1,38 @@
+use candid::CandidType;
+use icrc_ledger_types::icrc1::account::Account;
+use serde::Deserialize;
+
+use crate::impl_storable_for;
+
+impl_storable_for!(RecipientListEntry);
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum RecipientList {
+    /// Approved recipients, only enforced when enabled in the config
+    Allow,
+    /// Recipients that can never receive transfers
+    Deny,
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RecipientListEntry {
+    pub account: Account,
+    pub list: RecipientList,
+    pub created_at: u64,
+}
+
+impl RecipientListEntry {
+    pub fn new(account: Account, list: RecipientList) -> Self {
+        Self {
+            account,
+            list,
+            created_at: ic_cdk::api::time(),
+        }
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RecipientListProposalContent {
+    pub account: Account,
+    pub list: RecipientList,
+}
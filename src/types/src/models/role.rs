@@ -0,0 +1,90 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::impl_storable_for;
+
+impl_storable_for!(Roles);
+
+#[derive(CandidType, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    Proposer,
+    /// Only whitelisted members can vote, as only their votes count
+    Voter,
+    /// Can execute approved proposals of which the timer didn't succeed
+    Executor,
+    Viewer,
+    /// Can assign roles, except for the admin role itself, and refresh the group members
+    Admin,
+}
+
+#[derive(CandidType, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    Propose,
+    Vote,
+    Execute,
+    View,
+    /// Refreshing the cached group members, admins can't change the whitelist
+    RefreshGroup,
+    ManageRoles,
+}
+
+impl Role {
+    pub fn all() -> Vec<Role> {
+        vec![
+            Role::Proposer,
+            Role::Voter,
+            Role::Executor,
+            Role::Viewer,
+            Role::Admin,
+        ]
+    }
+
+    /// Every role can view the canister
+    pub fn permissions(&self) -> Vec<Permission> {
+        use Permission::*;
+
+        match self {
+            Role::Proposer => vec![Propose, View],
+            Role::Voter => vec![Vote, View],
+            Role::Executor => vec![Execute, View],
+            Role::Viewer => vec![View],
+            Role::Admin => vec![RefreshGroup, ManageRoles, View],
+        }
+    }
+}
+
+/// The roles explicitly assigned to a principal
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq, Default)]
+pub struct Roles(pub Vec<Role>);
+
+impl Roles {
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.0
+            .iter()
+            .any(|role| role.permissions().contains(&permission))
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RoleAssignment {
+    pub principal: Principal,
+    /// Roles assigned through `assign_roles`
+    pub roles: Vec<Role>,
+    /// Roles held as owner or whitelisted member
+    pub implicit_roles: Vec<Role>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Permission, Role, Roles};
+
+    #[test]
+    fn test_has_permission() {
+        let roles = Roles(vec![Role::Viewer, Role::Executor]);
+        assert!(roles.has_permission(Permission::View));
+        assert!(roles.has_permission(Permission::Execute));
+        assert!(!roles.has_permission(Permission::Vote));
+        assert!(!Roles::default().has_permission(Permission::View));
+        assert!(Roles(Role::all()).has_permission(Permission::ManageRoles));
+    }
+}
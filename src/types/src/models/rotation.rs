@@ -0,0 +1,31 @@
+use candid::{CandidType, Principal};
+use serde::Deserialize;
+
+use crate::impl_storable_for;
+
+impl_storable_for!(Rotation);
+
+/// A request of a member to move their membership to a new principal, keyed by the current
+/// principal of the member
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Rotation {
+    pub new: Principal,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+impl Rotation {
+    pub fn new(new: Principal, expires_at: u64) -> Self {
+        Self {
+            new,
+            created_at: ic_cdk::api::time(),
+            expires_at,
+        }
+    }
+
+    pub fn is_active(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
+}
+
+pub type RotationEntry = (Principal, Rotation);
@@ -0,0 +1,38 @@
+use candid::{CandidType, Nat, Principal};
+use icrc_ledger_types::icrc1::account::Account;
+use serde::Deserialize;
+
+use crate::impl_storable_for;
+
+impl_storable_for!(Spending);
+
+/// A transfer executed by the multisig, used to calculate the consumed spending limits
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Spending {
+    pub proposal_id: u64,
+    pub canister_id: Principal,
+    pub to: Account,
+    pub amount: Nat,
+    /// USD value in cents, based on the valuation of the proposal
+    pub usd_value: Option<Nat>,
+    pub created_at: u64,
+}
+
+impl Spending {
+    pub fn new(
+        proposal_id: u64,
+        canister_id: Principal,
+        to: Account,
+        amount: Nat,
+        usd_value: Option<Nat>,
+    ) -> Self {
+        Self {
+            proposal_id,
+            canister_id,
+            to,
+            amount,
+            usd_value,
+            created_at: ic_cdk::api::time(),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use candid::{CandidType, Nat, Principal};
+use icrc_ledger_types::icrc1::account::Account;
+use serde::Deserialize;
+
+use crate::{impl_storable_for, Spending, DAY_IN_NANOS};
+
+impl_storable_for!(SpendingLimit);
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum Period {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl Period {
+    pub fn as_nanos(&self) -> u64 {
+        let days = match self {
+            Period::Daily => 1,
+            Period::Weekly => 7,
+            Period::Monthly => 30,
+        };
+
+        days * DAY_IN_NANOS
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ExceedAction {
+    /// Proposals exceeding the limit can not be created or executed
+    Block,
+    /// Proposals exceeding the limit need approval of at least this percentage of the members
+    RequireApproval(u8),
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum Denomination {
+    /// Amounts are in the smallest unit of the ledger token
+    Token,
+    /// Amounts are in USD cents, converted with the exchange rate canister
+    Usd,
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct SpendingLimit {
+    pub canister_id: Principal,
+    /// When set, the limit only applies to transfers to this account
+    pub recipient: Option<Account>,
+    pub period: Period,
+    pub amount: Nat,
+    /// Optional for limits stored before USD limits existed, in tokens when not set
+    pub denomination: Option<Denomination>,
+    pub on_exceed: ExceedAction,
+}
+
+impl SpendingLimit {
+    pub fn denomination(&self) -> Denomination {
+        self.denomination.clone().unwrap_or(Denomination::Token)
+    }
+
+    /// Start of the rolling window of the limit that ends at `now`
+    pub fn window_start(&self, now: u64) -> u64 {
+        now.saturating_sub(self.period.as_nanos())
+    }
+
+    /// Sum of the spendings since `since` that count towards the limit, in the denomination of
+    /// the limit. Spendings recorded without a USD valuation, before the ledger had USD limits,
+    /// count as $0 towards USD limits
+    pub fn consumed<'a>(
+        &self,
+        spendings: impl IntoIterator<Item = &'a Spending>,
+        since: u64,
+    ) -> Nat {
+        spendings
+            .into_iter()
+            .filter(|spending| {
+                spending.canister_id == self.canister_id
+                    && spending.created_at >= since
+                    && self.recipient.as_ref().is_none_or(|r| r == &spending.to)
+            })
+            .fold(Nat::from(0u32), |acc, spending| match self.denomination() {
+                Denomination::Token => acc + spending.amount.clone(),
+                Denomination::Usd => acc + spending.usd_value.clone().unwrap_or_default(),
+            })
+    }
+}
+
+pub type SpendingLimitEntry = (u64, SpendingLimit);
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct SpendingAllowance {
+    pub id: u64,
+    pub limit: SpendingLimit,
+    pub consumed: Nat,
+    pub remaining: Nat,
+    pub window_start: u64,
+}
+
+#[cfg(test)]
+mod test {
+    use candid::{Nat, Principal};
+    use icrc_ledger_types::icrc1::account::Account;
+
+    use crate::{Denomination, ExceedAction, Period, Spending, SpendingLimit, DAY_IN_NANOS as DAY};
+
+    fn account(id: u8) -> Account {
+        Account {
+            owner: Principal::from_slice(&[id]),
+            subaccount: None,
+        }
+    }
+
+    fn spending(ledger: u8, to: u8, amount: u32, created_at: u64) -> Spending {
+        Spending {
+            proposal_id: 0,
+            canister_id: Principal::from_slice(&[ledger]),
+            to: account(to),
+            amount: Nat::from(amount),
+            usd_value: Some(Nat::from(amount * 2)),
+            created_at,
+        }
+    }
+
+    fn limit(recipient: Option<Account>, denomination: Denomination) -> SpendingLimit {
+        SpendingLimit {
+            canister_id: Principal::from_slice(&[1]),
+            recipient,
+            period: Period::Weekly,
+            amount: Nat::from(1000u32),
+            denomination: Some(denomination),
+            on_exceed: ExceedAction::Block,
+        }
+    }
+
+    #[test]
+    fn test_rolling_window() {
+        let now = 30 * DAY;
+        let limit = limit(None, Denomination::Token);
+        let since = limit.window_start(now);
+        assert_eq!(since, 23 * DAY);
+
+        let spendings = [
+            // Older than a week, no longer counts
+            spending(1, 2, 100, 23 * DAY - 1),
+            spending(1, 2, 200, 23 * DAY),
+            spending(1, 3, 300, now),
+            // Another ledger
+            spending(4, 2, 400, now),
+        ];
+
+        assert_eq!(limit.consumed(&spendings, since), Nat::from(500u32));
+    }
+
+    #[test]
+    fn test_consumed_by_recipient_and_denomination() {
+        let spendings = [spending(1, 2, 100, DAY), spending(1, 3, 200, DAY)];
+
+        let limit = self::limit(Some(account(2)), Denomination::Token);
+        assert_eq!(limit.consumed(&spendings, 0), Nat::from(100u32));
+
+        let limit = self::limit(None, Denomination::Usd);
+        assert_eq!(limit.consumed(&spendings, 0), Nat::from(600u32));
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use candid::CandidType;
+use candid::{CandidType, Nat, Principal};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Default, CandidType, Serialize, Deserialize)]
@@ -24,6 +24,10 @@ pub enum ValidationType {
     None,
     StringLength(String, usize, usize),
     Count(usize, usize, usize),
+    NonZero(Nat),
+    Range(u64, u64, u64),
+    ExcludedPrincipal(Principal, Vec<Principal>),
+    Unique(String, Vec<String>),
 }
 
 impl fmt::Display for ValidationType {
@@ -41,6 +45,20 @@ impl fmt::Display for ValidationType {
             Count(value, min, max) => {
                 write!(f, "Count - value: {}, min: {}, max: {}", value, min, max)
             }
+            NonZero(value) => write!(f, "NonZero - value: {}", value),
+            Range(value, min, max) => {
+                write!(f, "Range - value: {}, min: {}, max: {}", value, min, max)
+            }
+            ExcludedPrincipal(value, excluded) => {
+                write!(
+                    f,
+                    "ExcludedPrincipal - value: {}, excluded: {:?}",
+                    value, excluded
+                )
+            }
+            Unique(value, others) => {
+                write!(f, "Unique - value: {}, others: {:?}", value, others)
+            }
         }
     }
 }
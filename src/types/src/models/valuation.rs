@@ -0,0 +1,58 @@
+use candid::{CandidType, Nat};
+use serde::Deserialize;
+
+/// USD valuation of a proposal, based on the exchange rate at the time of creation
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct UsdValuation {
+    pub symbol: String,
+    /// Price of a single token in USD, with `rate_decimals` decimals
+    pub rate: u64,
+    pub rate_decimals: u32,
+    pub token_decimals: u8,
+    /// Timestamp of the exchange rate in seconds
+    pub timestamp: u64,
+    /// USD value of the proposal in cents
+    pub usd_value: Nat,
+}
+
+impl UsdValuation {
+    /// Converts an amount in the smallest token unit to USD cents, rounded down
+    pub fn to_usd_cents(&self, amount: &Nat) -> Nat {
+        let exponent = self.token_decimals as u32 + self.rate_decimals;
+        let divisor = Nat(Nat::from(10u32).0.pow(exponent));
+
+        amount.clone() * Nat::from(self.rate) * Nat::from(100u32) / divisor
+    }
+
+    /// The same exchange rate applied to another amount
+    pub fn with_amount(&self, amount: &Nat) -> Self {
+        Self {
+            usd_value: self.to_usd_cents(amount),
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use candid::Nat;
+
+    use super::UsdValuation;
+
+    #[test]
+    fn test_to_usd_cents() {
+        let valuation = UsdValuation {
+            symbol: "ICP".to_owned(),
+            // $12.345678900
+            rate: 12_345_678_900,
+            rate_decimals: 9,
+            token_decimals: 8,
+            timestamp: 0,
+            usd_value: Nat::from(0u32),
+        };
+
+        assert_eq!(valuation.to_usd_cents(&Nat::from(100_000_000u64)), 1234u32);
+        assert_eq!(valuation.to_usd_cents(&Nat::from(250_000_000u64)), 3086u32);
+        assert_eq!(valuation.to_usd_cents(&Nat::from(1u32)), 0u32);
+    }
+}
@@ -10,6 +10,8 @@ impl_storable_for!(Vote);
 pub enum VoteKind {
     Approve,
     Reject,
+    /// Counts towards the quorum, but not towards the approval ratio
+    Abstain,
 }
 
 #[derive(CandidType, Deserialize, PartialEq, Eq, Clone)]
@@ -17,19 +19,24 @@ pub struct Vote {
     pub voter: Principal,
     pub kind: VoteKind,
     pub created_at: u64,
+    pub rationale: Option<String>,
+    /// Set when the vote was changed after it was cast
+    pub updated_at: Option<u64>,
 }
 
 impl Vote {
-    pub fn new(voter: Principal, kind: VoteKind) -> Self {
+    pub fn new(voter: Principal, kind: VoteKind, rationale: Option<String>) -> Self {
         Self {
             voter,
             kind,
             created_at: time(),
+            rationale,
+            updated_at: None,
         }
     }
 }
 
-#[derive(CandidType, Clone, Deserialize, PartialEq, Eq)]
+#[derive(CandidType, Clone, Deserialize, PartialEq, Eq, Debug)]
 pub enum TallyResult {
     Approve,
     Reject,
@@ -0,0 +1,18 @@
+use candid::CandidType;
+use serde::Deserialize;
+
+use crate::{impl_storable_for, Vote, Votes};
+
+impl_storable_for!(VoteHistory);
+
+/// Every vote cast or changed on a proposal, in chronological order
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct VoteHistory(pub Vec<Vote>);
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct VotesResponse {
+    pub id: u64,
+    /// The effective vote of every voter
+    pub votes: Votes,
+    pub history: VoteHistory,
+}
@@ -1,7 +1,7 @@
 use candid::{CandidType, Principal};
 use serde::Deserialize;
 
-use crate::{impl_storable_for, Vote, VoteKind};
+use crate::{impl_storable_for, TallyResult, TallyRules, Vote, VoteKind};
 
 impl_storable_for!(Votes);
 
@@ -17,22 +17,300 @@ impl Votes {
         self.0.push(vote);
     }
 
-    pub fn update(&mut self, voter: Principal, kind: VoteKind) {
-        if let Some(vote) = self.0.iter_mut().find(|v| v.voter == voter) {
-            vote.kind = kind;
+    /// Replaces the kind and rationale of the existing vote of the voter, the original
+    /// `created_at` is kept and `updated_at` is set to the time of the new vote
+    pub fn update(&mut self, vote: Vote) {
+        if let Some(existing) = self.0.iter_mut().find(|v| v.voter == vote.voter) {
+            existing.kind = vote.kind;
+            existing.rationale = vote.rationale;
+            existing.updated_at = Some(vote.created_at);
+        }
+    }
+
+    /// Only the votes of the given members, votes of former members don't count
+    pub fn of_members(&self, members: &[Principal]) -> Votes {
+        Votes(
+            self.0
+                .iter()
+                .filter(|v| members.contains(&v.voter))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Moves the vote of a member to the new principal of the member
+    pub fn rotate_voter(&mut self, old: &Principal, new: Principal) {
+        if let Some(vote) = self.0.iter_mut().find(|v| &v.voter == old) {
+            vote.voter = new;
         }
     }
 
     pub fn approvals(&self) -> usize {
-        self.0
-            .iter()
-            .filter(|v| v.kind == VoteKind::Approve)
-            .count()
+        self.count(VoteKind::Approve)
     }
 
     pub fn rejections(&self) -> usize {
-        self.0.iter().filter(|v| v.kind == VoteKind::Reject).count()
+        self.count(VoteKind::Reject)
+    }
+
+    pub fn abstentions(&self) -> usize {
+        self.count(VoteKind::Abstain)
+    }
+
+    /// Adds a vote for every delegator that didn't vote, following the vote of their delegate.
+    /// Only direct votes of the delegates are followed, delegations don't chain.
+    /// # Arguments
+    /// * `delegations` - The active delegations as `(delegator, delegate)` pairs
+    pub fn with_delegations(&self, delegations: &[(Principal, Principal)]) -> Votes {
+        let mut votes = self.clone();
+
+        for (delegator, delegate) in delegations {
+            if self.voted(delegator) || votes.voted(delegator) {
+                continue;
+            }
+
+            if let Some(vote) = self.0.iter().find(|v| &v.voter == delegate) {
+                votes.add(Vote {
+                    voter: *delegator,
+                    ..vote.clone()
+                });
+            }
+        }
+
+        votes
+    }
+
+    /// Decides the outcome of the votes
+    /// # Arguments
+    /// * `members` - The amount of members that can vote
+    /// * `rules` - The required quorum and approval ratio, a majority of all members has to
+    ///   approve or reject when not set
+    /// * `threshold` - Percentage of the members that has to approve, on top of the rules
+    pub fn tally(
+        &self,
+        members: usize,
+        rules: Option<&TallyRules>,
+        threshold: Option<u8>,
+    ) -> TallyResult {
+        let approvals = self.approvals();
+        let rejections = self.rejections();
+        let participation = approvals + rejections + self.abstentions();
+        let decisive = approvals + rejections;
+
+        if members == 0 {
+            return TallyResult::NotReached;
+        }
+
+        let threshold_met = threshold.is_none_or(|t| approvals * 100 >= t as usize * members);
+
+        let Some(rules) = rules else {
+            if threshold_met && approvals * 100 > 50 * members {
+                return TallyResult::Approve;
+            }
+            if rejections * 100 > 50 * members {
+                return TallyResult::Reject;
+            }
+            if approvals * 2 == members && rejections * 2 == members {
+                return TallyResult::Deadlock;
+            }
+
+            return TallyResult::NotReached;
+        };
+
+        if participation * 100 < rules.quorum as usize * members || decisive == 0 {
+            return TallyResult::NotReached;
+        }
+
+        if threshold_met && approvals * 100 >= rules.approval as usize * decisive {
+            return TallyResult::Approve;
+        }
+        if rejections * 100 >= rules.approval as usize * decisive {
+            return TallyResult::Reject;
+        }
+        if approvals == rejections {
+            return TallyResult::Deadlock;
+        }
+
+        TallyResult::NotReached
+    }
+
+    fn count(&self, kind: VoteKind) -> usize {
+        self.0.iter().filter(|v| v.kind == kind).count()
     }
 }
 
 pub type VotesEntry = (u64, Votes);
+
+#[cfg(test)]
+mod test {
+    use candid::Principal;
+
+    use crate::{TallyResult, TallyRules, Vote, VoteKind, Votes};
+
+    fn votes(kinds: &[VoteKind]) -> Votes {
+        Votes(
+            kinds
+                .iter()
+                .enumerate()
+                .map(|(i, kind)| Vote {
+                    voter: Principal::from_slice(&[i as u8]),
+                    kind: kind.clone(),
+                    created_at: 0,
+                    rationale: None,
+                    updated_at: None,
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_tally() {
+        use VoteKind::*;
+
+        // A majority of all members has to approve or reject by default
+        assert_eq!(
+            votes(&[Approve]).tally(3, None, None),
+            TallyResult::NotReached
+        );
+        assert_eq!(
+            votes(&[Approve, Approve]).tally(3, None, None),
+            TallyResult::Approve
+        );
+        assert_eq!(
+            votes(&[Reject, Reject]).tally(3, None, None),
+            TallyResult::Reject
+        );
+        assert_eq!(
+            votes(&[Approve, Reject]).tally(2, None, None),
+            TallyResult::Deadlock
+        );
+        assert_eq!(
+            votes(&[Approve, Reject]).tally(3, None, None),
+            TallyResult::NotReached
+        );
+        assert_eq!(
+            votes(&[Approve, Abstain]).tally(3, None, None),
+            TallyResult::NotReached
+        );
+        assert_eq!(
+            votes(&[Approve, Approve, Reject]).tally(5, None, None),
+            TallyResult::NotReached
+        );
+
+        // A threshold only raises the bar
+        assert_eq!(
+            votes(&[Approve, Approve]).tally(3, None, Some(100)),
+            TallyResult::NotReached
+        );
+        assert_eq!(
+            votes(&[Approve, Reject, Reject]).tally(3, None, Some(51)),
+            TallyResult::Reject
+        );
+    }
+
+    #[test]
+    fn test_tally_with_rules() {
+        use VoteKind::*;
+        let rules = TallyRules {
+            quorum: 51,
+            approval: 51,
+        };
+        let rules = Some(&rules);
+
+        assert_eq!(
+            votes(&[Approve]).tally(3, rules, None),
+            TallyResult::NotReached
+        );
+        assert_eq!(
+            votes(&[Approve, Approve]).tally(3, rules, None),
+            TallyResult::Approve
+        );
+        assert_eq!(
+            votes(&[Approve, Reject]).tally(3, rules, None),
+            TallyResult::Deadlock
+        );
+        assert_eq!(
+            votes(&[Approve, Reject, Reject]).tally(3, rules, None),
+            TallyResult::Reject
+        );
+
+        // Abstentions count towards the quorum only
+        assert_eq!(
+            votes(&[Approve, Abstain]).tally(3, rules, None),
+            TallyResult::Approve
+        );
+        assert_eq!(
+            votes(&[Reject, Abstain]).tally(3, rules, None),
+            TallyResult::Reject
+        );
+        assert_eq!(
+            votes(&[Abstain, Abstain]).tally(3, rules, None),
+            TallyResult::NotReached
+        );
+
+        // A threshold requires approval of a share of all members
+        assert_eq!(
+            votes(&[Approve, Abstain]).tally(3, rules, Some(60)),
+            TallyResult::NotReached
+        );
+        assert_eq!(
+            votes(&[Approve, Approve]).tally(3, rules, Some(60)),
+            TallyResult::Approve
+        );
+        assert_eq!(
+            votes(&[Approve, Reject, Reject]).tally(3, rules, Some(51)),
+            TallyResult::Reject
+        );
+    }
+
+    #[test]
+    fn test_with_delegations() {
+        use VoteKind::*;
+        let principal = |i: u8| Principal::from_slice(&[i]);
+
+        // Voter 0 approved, voter 1 rejected
+        let direct = votes(&[Approve, Reject]);
+
+        // Voter 2 follows voter 0
+        let votes = direct.with_delegations(&[(principal(2), principal(0))]);
+        assert_eq!(votes.approvals(), 2);
+
+        // Voter 1 voted directly, so the delegation is ignored
+        let votes = direct.with_delegations(&[(principal(1), principal(0))]);
+        assert_eq!(votes.approvals(), 1);
+        assert_eq!(votes.rejections(), 1);
+
+        // Delegations don't chain, voter 3 delegated to voter 2 who didn't vote directly
+        let votes =
+            direct.with_delegations(&[(principal(2), principal(0)), (principal(3), principal(2))]);
+        assert_eq!(votes.0.len(), 3);
+    }
+
+    #[test]
+    fn test_of_members() {
+        use VoteKind::*;
+        let principal = |i: u8| Principal::from_slice(&[i]);
+
+        // Voter 1 is no longer a member
+        let votes = votes(&[Approve, Reject, Approve]).of_members(&[principal(0), principal(2)]);
+        assert_eq!(votes.approvals(), 2);
+        assert_eq!(votes.rejections(), 0);
+    }
+
+    #[test]
+    fn test_rotate_voter() {
+        use VoteKind::*;
+        let principal = |i: u8| Principal::from_slice(&[i]);
+
+        let mut votes = votes(&[Approve, Reject]);
+        votes.rotate_voter(&principal(1), principal(9));
+        assert!(votes.voted(&principal(9)));
+        assert!(!votes.voted(&principal(1)));
+        assert_eq!(votes.rejections(), 1);
+
+        // Members that didn't vote have nothing to move
+        votes.rotate_voter(&principal(5), principal(6));
+        assert!(!votes.voted(&principal(6)));
+        assert_eq!(votes.0.len(), 2);
+    }
+}